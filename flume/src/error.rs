@@ -0,0 +1,159 @@
+//! Errors of the channel wrappers.
+
+use std::fmt;
+
+/// Error of a channel operation, holding the message if it wasn't sent.
+///
+/// Converts from the [`flume`] errors, and back with [`TryFrom`] when the
+/// variant has a [`flume`] counterpart.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_flume::ChannelError;
+///
+/// let (tx, rx) = leak_playground_flume::bounded(1);
+/// assert_eq!(rx.try_recv(), Err(ChannelError::Empty));
+/// tx.send(1).unwrap();
+/// assert_eq!(tx.try_send(2), Err(ChannelError::Full(2)));
+/// drop(rx);
+/// assert_eq!(tx.send(3), Err(ChannelError::Disconnected(Some(3))));
+///
+/// let err = tx.try_send(4).unwrap_err();
+/// assert_eq!(
+///     flume::TrySendError::try_from(err),
+///     Ok(flume::TrySendError::Disconnected(4)),
+/// );
+/// assert_eq!(flume::SendError::try_from(ChannelError::Full(5)), Err(ChannelError::Full(5)));
+/// assert_eq!(
+///     flume::TryRecvError::try_from(ChannelError::<i32>::Empty),
+///     Ok(flume::TryRecvError::Empty),
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ChannelError<T> {
+    /// The other side of the channel is gone. Holds the message when sending.
+    Disconnected(Option<T>),
+    /// The channel is bounded and full.
+    Full(T),
+    /// The channel is empty.
+    Empty,
+}
+
+impl<T> ChannelError<T> {
+    /// Gets the message which couldn't be sent, if any.
+    pub fn into_inner(self) -> Option<T> {
+        match self {
+            ChannelError::Disconnected(msg) => msg,
+            ChannelError::Full(msg) => Some(msg),
+            ChannelError::Empty => None,
+        }
+    }
+
+    pub fn is_disconnected(&self) -> bool {
+        matches!(self, ChannelError::Disconnected(_))
+    }
+}
+
+impl<T> From<flume::SendError<T>> for ChannelError<T> {
+    fn from(err: flume::SendError<T>) -> Self {
+        ChannelError::Disconnected(Some(err.into_inner()))
+    }
+}
+
+impl<T> From<flume::TrySendError<T>> for ChannelError<T> {
+    fn from(err: flume::TrySendError<T>) -> Self {
+        match err {
+            flume::TrySendError::Full(msg) => ChannelError::Full(msg),
+            flume::TrySendError::Disconnected(msg) => ChannelError::Disconnected(Some(msg)),
+        }
+    }
+}
+
+impl<T> From<flume::RecvError> for ChannelError<T> {
+    fn from(err: flume::RecvError) -> Self {
+        match err {
+            flume::RecvError::Disconnected => ChannelError::Disconnected(None),
+        }
+    }
+}
+
+impl<T> From<flume::TryRecvError> for ChannelError<T> {
+    fn from(err: flume::TryRecvError) -> Self {
+        match err {
+            flume::TryRecvError::Empty => ChannelError::Empty,
+            flume::TryRecvError::Disconnected => ChannelError::Disconnected(None),
+        }
+    }
+}
+
+impl<T> TryFrom<ChannelError<T>> for flume::SendError<T> {
+    type Error = ChannelError<T>;
+
+    fn try_from(err: ChannelError<T>) -> Result<Self, Self::Error> {
+        match err {
+            ChannelError::Disconnected(Some(msg)) => Ok(flume::SendError(msg)),
+            err => Err(err),
+        }
+    }
+}
+
+impl<T> TryFrom<ChannelError<T>> for flume::TrySendError<T> {
+    type Error = ChannelError<T>;
+
+    fn try_from(err: ChannelError<T>) -> Result<Self, Self::Error> {
+        match err {
+            ChannelError::Full(msg) => Ok(flume::TrySendError::Full(msg)),
+            ChannelError::Disconnected(Some(msg)) => Ok(flume::TrySendError::Disconnected(msg)),
+            err => Err(err),
+        }
+    }
+}
+
+impl<T> TryFrom<ChannelError<T>> for flume::RecvError {
+    type Error = ChannelError<T>;
+
+    fn try_from(err: ChannelError<T>) -> Result<Self, Self::Error> {
+        match err {
+            ChannelError::Disconnected(None) => Ok(flume::RecvError::Disconnected),
+            err => Err(err),
+        }
+    }
+}
+
+impl<T> TryFrom<ChannelError<T>> for flume::TryRecvError {
+    type Error = ChannelError<T>;
+
+    fn try_from(err: ChannelError<T>) -> Result<Self, Self::Error> {
+        match err {
+            ChannelError::Empty => Ok(flume::TryRecvError::Empty),
+            ChannelError::Disconnected(None) => Ok(flume::TryRecvError::Disconnected),
+            err => Err(err),
+        }
+    }
+}
+
+/// Doesn't require `T: Debug`, same as the [`flume`] errors.
+impl<T> fmt::Debug for ChannelError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChannelError::Disconnected(Some(_)) => f.write_str("Disconnected(Some(..))"),
+            ChannelError::Disconnected(None) => f.write_str("Disconnected(None)"),
+            ChannelError::Full(_) => f.write_str("Full(..)"),
+            ChannelError::Empty => f.write_str("Empty"),
+        }
+    }
+}
+
+impl<T> fmt::Display for ChannelError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChannelError::Disconnected(_) => f.write_str("channel is disconnected"),
+            ChannelError::Full(_) => f.write_str("channel is full"),
+            ChannelError::Empty => f.write_str("channel is empty"),
+        }
+    }
+}
+
+/// Has no source, the [`flume`] error is recovered with [`TryFrom`] instead.
+impl<T> std::error::Error for ChannelError<T> {}
@@ -0,0 +1,77 @@
+//! Futures of the channel wrappers.
+
+use std::{
+    future::Future,
+    pin::{self, Pin},
+    task,
+};
+
+use leak_playground_std::marker::Forget;
+
+use crate::ChannelError;
+
+/// Future of sending an item, resolving to [`ChannelError`] on failure.
+///
+/// # Examples
+///
+/// ```
+/// use std::{future::Future, pin::pin, task};
+/// use leak_playground_flume::ChannelError;
+///
+/// let mut cx = task::Context::from_waker(task::Waker::noop());
+/// let (tx, rx) = leak_playground_flume::bounded(1);
+/// assert_eq!(pin!(tx.send_async(1)).poll(&mut cx), task::Poll::Ready(Ok(())));
+/// drop(rx);
+/// assert_eq!(
+///     pin!(tx.send_async(2)).poll(&mut cx),
+///     task::Poll::Ready(Err(ChannelError::Disconnected(Some(2)))),
+/// );
+/// ```
+pub struct SendFut<'a, T> {
+    pub(crate) inner: flume::r#async::SendFut<'a, T>,
+}
+
+impl<'a, T> Future for SendFut<'a, T> {
+    type Output = Result<(), ChannelError<T>>;
+
+    fn poll(self: pin::Pin<&mut Self>, cx: &mut task::Context<'_>) -> task::Poll<Self::Output> {
+        unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().inner) }
+            .poll(cx)
+            .map(|res| Ok(res?))
+    }
+}
+
+unsafe impl<T: Forget> Forget for SendFut<'_, T> {}
+
+/// Future of receiving an item, resolving to [`ChannelError`] on failure.
+///
+/// # Examples
+///
+/// ```
+/// use std::{future::Future, pin::pin, task};
+/// use leak_playground_flume::ChannelError;
+///
+/// let mut cx = task::Context::from_waker(task::Waker::noop());
+/// let (tx, rx) = leak_playground_flume::rendezvous::<i32>();
+/// assert!(pin!(rx.recv_async()).poll(&mut cx).is_pending());
+/// drop(tx);
+/// assert_eq!(
+///     pin!(rx.recv_async()).poll(&mut cx),
+///     task::Poll::Ready(Err(ChannelError::Disconnected(None))),
+/// );
+/// ```
+pub struct RecvFut<'a, T> {
+    pub(crate) inner: flume::r#async::RecvFut<'a, T>,
+}
+
+impl<'a, T> Future for RecvFut<'a, T> {
+    type Output = Result<T, ChannelError<T>>;
+
+    fn poll(self: pin::Pin<&mut Self>, cx: &mut task::Context<'_>) -> task::Poll<Self::Output> {
+        unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().inner) }
+            .poll(cx)
+            .map(|res| Ok(res?))
+    }
+}
+
+unsafe impl<T: Forget> Forget for RecvFut<'_, T> {}
@@ -1,7 +1,14 @@
+use std::fmt;
+
 use leak_playground_std::marker::Forget;
 
+mod error;
+mod future;
 pub mod rendezvous;
+pub use error::ChannelError;
+pub use future::{RecvFut, SendFut};
 pub use rendezvous::rendezvous;
+pub mod thread;
 
 /// Create a bounded channel.
 pub fn bounded<T: Forget>(cap: usize) -> (Sender<T>, Receiver<T>) {
@@ -40,20 +47,200 @@ pub struct Sender<T> {
 }
 
 impl<T> Sender<T> {
-    pub fn send(&self, msg: T) -> Result<(), flume::SendError<T>> {
-        self.inner.send(msg)
+    /// Wraps a [`flume`] sender.
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_flume::Sender;
+    ///
+    /// let (tx, rx) = flume::unbounded();
+    /// let tx = Sender::from_flume(tx);
+    /// tx.send(42).unwrap();
+    /// let tx: flume::Sender<i32> = tx.into_flume();
+    /// tx.send(43).unwrap();
+    /// assert_eq!(rx.drain().collect::<Vec<_>>(), [42, 43]);
+    /// ```
+    pub fn from_flume(inner: flume::Sender<T>) -> Self
+    where
+        T: Forget,
+    {
+        Sender { inner }
+    }
+
+    /// Wraps a [`flume`] sender for the unforgettable parameter type `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not take ownership over itself.
+    pub unsafe fn from_flume_unchecked(inner: flume::Sender<T>) -> Self {
+        Sender { inner }
+    }
+
+    /// Unwraps the [`flume`] sender.
+    pub fn into_flume(self) -> flume::Sender<T>
+    where
+        T: Forget,
+    {
+        self.inner
+    }
+
+    /// Unwraps the [`flume`] sender for the unforgettable parameter type
+    /// `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not take ownership over itself.
+    pub unsafe fn into_flume_unchecked(self) -> flume::Sender<T> {
+        self.inner
+    }
+
+    pub fn send(&self, msg: T) -> Result<(), ChannelError<T>> {
+        Ok(self.inner.send(msg)?)
+    }
+
+    pub fn try_send(&self, msg: T) -> Result<(), ChannelError<T>> {
+        Ok(self.inner.try_send(msg)?)
+    }
+
+    /// Sends every item, stopping at the first one which couldn't be sent
+    /// because the channel is disconnected.
+    ///
+    /// Pass the iterator by `&mut` to keep the items after the failed one.
+    /// For bounded channels this blocks per item, as capacity allows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (tx, rx) = leak_playground_flume::unbounded();
+    /// tx.send_all([1, 2]).unwrap();
+    /// assert_eq!(rx.recv_all_available(), [1, 2]);
+    ///
+    /// drop(rx);
+    /// let mut items = [3, 4, 5].into_iter();
+    /// let err = tx.send_all(&mut items).unwrap_err();
+    /// assert_eq!(err.into_inner(), Some(3));
+    /// assert_eq!(items.collect::<Vec<_>>(), [4, 5]);
+    /// ```
+    ///
+    /// ```
+    /// let (tx, rx) = leak_playground_flume::bounded(2);
+    /// let consumer = std::thread::spawn(move || {
+    ///     (0..5).map(|_| rx.recv().unwrap()).collect::<Vec<_>>()
+    /// });
+    /// tx.send_all(vec![1, 2, 3, 4, 5]).unwrap();
+    /// assert_eq!(consumer.join().unwrap(), [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn send_all<I>(&self, iter: I) -> Result<(), ChannelError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().try_for_each(|msg| self.send(msg))
+    }
+
+    pub fn send_async(&self, item: T) -> SendFut<'_, T> {
+        SendFut {
+            inner: self.inner.send_async(item),
+        }
+    }
+
+    pub fn into_send_async<'a>(self, item: T) -> SendFut<'a, T> {
+        SendFut {
+            inner: self.inner.into_send_async(item),
+        }
     }
 
-    pub fn try_send(&self, msg: T) -> Result<(), flume::TrySendError<T>> {
-        self.inner.try_send(msg)
+    /// Number of senders connected to the channel.
+    ///
+    /// Counts leaked clones too, so it helps to spot one keeping the channel
+    /// alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (tx, _rx) = leak_playground_flume::unbounded::<i32>();
+    /// assert_eq!(tx.sender_count(), 1);
+    /// let other = tx.clone();
+    /// assert_eq!(tx.sender_count(), 2);
+    /// drop(other);
+    /// assert_eq!(tx.sender_count(), 1);
+    /// ```
+    pub fn sender_count(&self) -> usize {
+        self.inner.sender_count()
     }
 
-    pub fn send_async(&self, item: T) -> flume::r#async::SendFut<T> {
-        self.inner.send_async(item)
+    /// Number of receivers connected to the channel.
+    pub fn receiver_count(&self) -> usize {
+        self.inner.receiver_count()
     }
 
-    pub fn into_send_async<'a>(self, item: T) -> flume::r#async::SendFut<'a, T> {
-        self.inner.into_send_async(item)
+    /// Channel's capacity, which is `None` for unbounded channels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (tx, _rx) = leak_playground_flume::bounded(2);
+    /// assert_eq!(tx.capacity(), Some(2));
+    /// tx.send(1).unwrap();
+    /// assert!(!tx.is_full());
+    /// tx.send(2).unwrap();
+    /// assert!(tx.is_full());
+    /// ```
+    pub fn capacity(&self) -> Option<usize> {
+        self.inner.capacity()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    /// Whether both senders belong to the same channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (tx, _rx) = leak_playground_flume::unbounded::<i32>();
+    /// let (other, _other_rx) = leak_playground_flume::unbounded::<i32>();
+    /// assert!(tx.same_channel(&tx.clone()));
+    /// assert!(!tx.same_channel(&other));
+    /// ```
+    pub fn same_channel(&self, other: &Self) -> bool {
+        self.inner.same_channel(&other.inner)
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Sender {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Shows channel's length and capacity, which is `None` for unbounded
+/// channels.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// struct Config {
+///     jobs: leak_playground_flume::Sender<i32>,
+/// }
+///
+/// let (tx, _rx) = leak_playground_flume::bounded(2);
+/// tx.send(1).unwrap();
+/// let config = Config { jobs: tx };
+/// assert_eq!(
+///     format!("{config:?}"),
+///     "Config { jobs: Sender { len: 1, capacity: Some(2) } }",
+/// );
+/// ```
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender")
+            .field("len", &self.inner.len())
+            .field("capacity", &self.inner.capacity())
+            .finish()
     }
 }
 
@@ -62,20 +249,163 @@ pub struct Receiver<T> {
 }
 
 impl<T> Receiver<T> {
-    pub fn recv(&self) -> Result<T, flume::RecvError> {
-        self.inner.recv()
+    /// Wraps a [`flume`] receiver.
+    pub fn from_flume(inner: flume::Receiver<T>) -> Self
+    where
+        T: Forget,
+    {
+        Receiver { inner }
+    }
+
+    /// Wraps a [`flume`] receiver for the unforgettable parameter type `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not take ownership over itself.
+    pub unsafe fn from_flume_unchecked(inner: flume::Receiver<T>) -> Self {
+        Receiver { inner }
+    }
+
+    /// Unwraps the [`flume`] receiver.
+    pub fn into_flume(self) -> flume::Receiver<T>
+    where
+        T: Forget,
+    {
+        self.inner
+    }
+
+    /// Unwraps the [`flume`] receiver for the unforgettable parameter type
+    /// `T`.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not take ownership over itself.
+    pub unsafe fn into_flume_unchecked(self) -> flume::Receiver<T> {
+        self.inner
+    }
+
+    pub fn recv(&self) -> Result<T, ChannelError<T>> {
+        Ok(self.inner.recv()?)
+    }
+
+    pub fn try_recv(&self) -> Result<T, ChannelError<T>> {
+        Ok(self.inner.try_recv()?)
+    }
+
+    /// Blocking iterator over the received items, which ends once all
+    /// senders are gone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (tx, rx) = leak_playground_flume::unbounded();
+    /// tx.send_all([1, 2, 3]).unwrap();
+    /// drop(tx);
+    /// assert_eq!(rx.iter().sum::<i32>(), 6);
+    ///
+    /// let (tx, rx) = leak_playground_flume::bounded(1);
+    /// let producer = std::thread::spawn(move || tx.send_all(0..5).unwrap());
+    /// let mut received = Vec::new();
+    /// for x in rx {
+    ///     received.push(x);
+    /// }
+    /// assert_eq!(received, [0, 1, 2, 3, 4]);
+    /// producer.join().unwrap();
+    /// ```
+    pub fn iter(&self) -> flume::Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Takes all the items currently in the channel without blocking.
+    pub fn recv_all_available(&self) -> Vec<T> {
+        self.inner.drain().collect()
+    }
+
+    pub fn recv_async(&self) -> RecvFut<'_, T> {
+        RecvFut {
+            inner: self.inner.recv_async(),
+        }
+    }
+
+    pub fn into_recv_async<'a>(self) -> RecvFut<'a, T> {
+        RecvFut {
+            inner: self.inner.into_recv_async(),
+        }
+    }
+
+    /// Number of senders connected to the channel.
+    pub fn sender_count(&self) -> usize {
+        self.inner.sender_count()
     }
 
-    pub fn try_recv(&self) -> Result<T, flume::TryRecvError> {
-        self.inner.try_recv()
+    /// Number of receivers connected to the channel.
+    ///
+    /// Counts leaked clones too, so it helps to spot one keeping the channel
+    /// alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (_tx, rx) = leak_playground_flume::unbounded::<i32>();
+    /// assert_eq!(rx.receiver_count(), 1);
+    /// let other = rx.clone();
+    /// assert_eq!(rx.receiver_count(), 2);
+    /// drop(other);
+    /// assert_eq!(rx.receiver_count(), 1);
+    /// ```
+    pub fn receiver_count(&self) -> usize {
+        self.inner.receiver_count()
     }
 
-    pub fn recv_async(&self) -> flume::r#async::RecvFut<'_, T> {
-        self.inner.recv_async()
+    /// Channel's capacity, which is `None` for unbounded channels.
+    pub fn capacity(&self) -> Option<usize> {
+        self.inner.capacity()
     }
 
-    pub fn into_recv_async<'a>(self) -> flume::r#async::RecvFut<'a, T> {
-        self.inner.into_recv_async()
+    pub fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    /// Whether both receivers belong to the same channel.
+    pub fn same_channel(&self, other: &Self) -> bool {
+        self.inner.same_channel(&other.inner)
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = flume::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = flume::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        Receiver {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Shows channel's length and capacity, which is `None` for unbounded
+/// channels.
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver")
+            .field("len", &self.inner.len())
+            .field("capacity", &self.inner.capacity())
+            .finish()
     }
 }
 
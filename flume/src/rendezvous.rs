@@ -23,14 +23,11 @@
 //! }
 //! ```
 
-use std::{
-    future::Future,
-    pin::{self, Pin},
-    task,
-};
-
 use leak_playground_std::marker::Forget;
 
+pub use crate::future::{RecvFut, SendFut};
+use crate::ChannelError;
+
 /// Create a rendezvous channel.
 pub fn rendezvous<T>() -> (Sender<T>, Receiver<T>) {
     let (tx, rx) = flume::bounded(0);
@@ -43,23 +40,58 @@ pub struct Sender<T> {
 
 unsafe impl<T> Forget for Sender<T> {}
 
+/// Each send is still matched by exactly one receive, whichever sender it
+/// comes from.
+///
+/// # Examples
+///
+/// ```
+/// let (tx, rx) = leak_playground_flume::rendezvous();
+/// let other = tx.clone();
+/// assert!(tx.same_channel(&other));
+/// let first = std::thread::spawn(move || tx.send(1).unwrap());
+/// let second = std::thread::spawn(move || other.send(2).unwrap());
+/// let mut received = [rx.recv().unwrap(), rx.recv().unwrap()];
+/// received.sort();
+/// assert_eq!(received, [1, 2]);
+/// first.join().unwrap();
+/// second.join().unwrap();
+/// assert!(rx.try_recv().is_err());
+/// ```
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Sender {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<T> Sender<T> {
-    pub fn send(&self, msg: T) -> Result<(), flume::SendError<T>> {
-        self.inner.send(msg)
+    pub fn send(&self, msg: T) -> Result<(), ChannelError<T>> {
+        Ok(self.inner.send(msg)?)
     }
 
-    pub fn try_send(&self, msg: T) -> Result<(), flume::TrySendError<T>> {
-        self.inner.try_send(msg)
+    pub fn try_send(&self, msg: T) -> Result<(), ChannelError<T>> {
+        Ok(self.inner.try_send(msg)?)
     }
 
     /// Asynchronously send an item.
-    pub fn send_async(&self, item: T) -> flume::r#async::SendFut<T> {
-        self.inner.send_async(item)
+    pub fn send_async(&self, item: T) -> SendFut<'_, T> {
+        SendFut {
+            inner: self.inner.send_async(item),
+        }
     }
 
     /// Asynchronously send an item and consume the sender.
-    pub fn into_send_async<'a>(self, item: T) -> flume::r#async::SendFut<'a, T> {
-        self.inner.into_send_async(item)
+    pub fn into_send_async<'a>(self, item: T) -> SendFut<'a, T> {
+        SendFut {
+            inner: self.inner.into_send_async(item),
+        }
+    }
+
+    /// Whether both senders belong to the same channel.
+    pub fn same_channel(&self, other: &Self) -> bool {
+        self.inner.same_channel(&other.inner)
     }
 }
 
@@ -69,13 +101,21 @@ pub struct Receiver<T> {
 
 unsafe impl<T> Forget for Receiver<T> {}
 
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        Receiver {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<T> Receiver<T> {
-    pub fn recv(&self) -> Result<T, flume::RecvError> {
-        self.inner.recv()
+    pub fn recv(&self) -> Result<T, ChannelError<T>> {
+        Ok(self.inner.recv()?)
     }
 
-    pub fn try_recv(&self) -> Result<T, flume::TryRecvError> {
-        self.inner.try_recv()
+    pub fn try_recv(&self) -> Result<T, ChannelError<T>> {
+        Ok(self.inner.try_recv()?)
     }
 
     /// Asynchronously receive an item.
@@ -119,18 +159,9 @@ impl<T> Receiver<T> {
             inner: self.inner.into_recv_async(),
         }
     }
-}
 
-pub struct RecvFut<'a, T> {
-    inner: flume::r#async::RecvFut<'a, T>,
-}
-
-impl<'a, T> Future for RecvFut<'a, T> {
-    type Output = Result<T, flume::RecvError>;
-
-    fn poll(self: pin::Pin<&mut Self>, cx: &mut task::Context<'_>) -> task::Poll<Self::Output> {
-        unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().inner) }.poll(cx)
+    /// Whether both receivers belong to the same channel.
+    pub fn same_channel(&self, other: &Self) -> bool {
+        self.inner.same_channel(&other.inner)
     }
 }
-
-unsafe impl<T: Forget> Forget for RecvFut<'_, T> {}
@@ -0,0 +1,43 @@
+//! Threads delivering their output through a channel.
+
+use leak_playground_std::marker::Forget;
+use leak_playground_std::thread::{self, JoinGuard};
+
+use crate::Receiver;
+
+/// Spawn a borrowing thread, which sends its return value on the returned
+/// channel instead of through the guard.
+///
+/// The guard still joins the thread on drop. If the thread panics, the
+/// sender is dropped and the receiver observes disconnection.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_flume::thread;
+///
+/// let numbers = vec![1, 2, 3];
+/// let (thrd, rx) = thread::spawn_scoped_channel(|| numbers.iter().sum::<i32>());
+/// assert_eq!(rx.recv(), Ok(6));
+/// thrd.join().unwrap();
+/// ```
+///
+/// ```
+/// use leak_playground_flume::thread;
+///
+/// let (thrd, rx) = thread::spawn_scoped_channel(|| -> i32 { panic!() });
+/// assert!(thrd.join().is_err());
+/// assert!(rx.recv().is_err());
+/// ```
+pub fn spawn_scoped_channel<'a, F, T>(f: F) -> (JoinGuard<'a, ()>, Receiver<T>)
+where
+    F: FnOnce() -> T + Send + 'a,
+    T: Send + Forget + 'a,
+{
+    let (tx, rx) = crate::bounded(1);
+    let thrd = thread::spawn_scoped(move || {
+        // Receiver may be gone already, which is fine
+        let _ = tx.send(f());
+    });
+    (thrd, rx)
+}
@@ -0,0 +1,119 @@
+//! Possible [`std::boxed`] replacements.
+
+use core::fmt;
+
+use crate::marker::Forget;
+
+/// A [`Box`] which can only be leaked if its value is forgettable.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::boxed::GuardedBox;
+///
+/// let b = GuardedBox::new(41);
+/// let x = GuardedBox::leak(b);
+/// *x += 1;
+/// assert_eq!(*x, 42);
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::{boxed::GuardedBox, thread};
+/// fn _leak_guard<'a>(thrd: thread::JoinGuard<'a, ()>) {
+///     GuardedBox::leak(GuardedBox::new(thrd));
+/// }
+/// ```
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GuardedBox<T> {
+    inner: Box<T>,
+}
+
+impl<T> GuardedBox<T> {
+    /// Constructs a new `GuardedBox<T>`.
+    pub fn new(x: T) -> Self {
+        GuardedBox { inner: Box::new(x) }
+    }
+
+    /// Moves the value out of the box.
+    pub fn into_inner(b: Self) -> T {
+        *b.inner
+    }
+
+    /// Leaks the box, returning a mutable reference to its value.
+    pub fn leak<'a>(b: Self) -> &'a mut T
+    where
+        T: Forget + 'a,
+    {
+        Box::leak(b.inner)
+    }
+
+    /// Consumes the box, returning a raw pointer to its value.
+    pub fn into_raw(b: Self) -> *mut T
+    where
+        T: Forget,
+    {
+        Box::into_raw(b.inner)
+    }
+
+    /// Constructs a box from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must come from [`GuardedBox::into_raw`] and must not be used
+    /// afterwards.
+    pub unsafe fn from_raw(raw: *mut T) -> Self {
+        GuardedBox {
+            inner: Box::from_raw(raw),
+        }
+    }
+}
+
+impl<T> AsRef<T> for GuardedBox<T> {
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T> AsMut<T> for GuardedBox<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T> std::ops::Deref for GuardedBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::ops::DerefMut for GuardedBox<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T> fmt::Display for GuardedBox<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<T> fmt::Debug for GuardedBox<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<T> fmt::Pointer for GuardedBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.inner, f)
+    }
+}
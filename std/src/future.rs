@@ -1,3 +1,5 @@
+//! Possible [`core::future`] additions. Contains examples.
+//!
 //! # Examples
 //!
 //! `Forget` future with internal unforget logic.
@@ -54,3 +56,134 @@
 //! }
 //! ```
 //!
+
+use core::{future::Future, marker::PhantomData, pin::Pin, task};
+
+use crate::marker::Forget;
+#[cfg(feature = "std")]
+use crate::marker::Unforget;
+
+/// Pins a forgettable future into a box, erasing its type.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::future::box_pin_forget;
+/// use leak_playground_std::marker::Forget;
+/// fn assert_forget<T: Forget + ?Sized>(_: &T) {}
+///
+/// let fut = box_pin_forget(std::future::ready(42));
+/// assert_forget(&fut);
+/// ```
+///
+/// Unforgettable futures are rejected:
+///
+/// ```compile_fail
+/// use leak_playground_std::future::box_pin_forget;
+/// use leak_playground_std::marker::Unforget;
+///
+/// let local = 42;
+/// let _fut = box_pin_forget(Unforget::new(std::future::ready(&local)));
+/// ```
+#[cfg(feature = "std")]
+pub fn box_pin_forget<'a, F>(f: F) -> Pin<Box<dyn Future<Output = F::Output> + Forget + 'a>>
+where
+    F: Future + Forget + 'a,
+{
+    Box::pin(f)
+}
+
+/// Pins a possibly unforgettable future into a box, erasing its type. The box
+/// can only be forgotten while `'a` is alive.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::future::box_pin_unforget;
+/// use leak_playground_std::marker::{Forget, Unforget};
+/// fn assert_forget<T: Forget + ?Sized>(_: &T) {}
+///
+/// let local = 42;
+/// let fut = box_pin_unforget(Unforget::new(std::future::ready(&local)));
+/// assert_forget(&fut);
+/// ```
+#[cfg(feature = "std")]
+pub fn box_pin_unforget<'a, F>(f: F) -> Pin<Box<dyn Future<Output = F::Output> + Forget + 'a>>
+where
+    F: Future + 'a,
+{
+    Box::pin(Unforget::<'a, F>::with_lifetime(f))
+}
+
+/// Creates a future which never resolves.
+///
+/// # Examples
+///
+/// ```
+/// use std::{future::Future, pin::pin, task};
+/// use leak_playground_std::future::pending_forget;
+///
+/// let mut cx = task::Context::from_waker(task::Waker::noop());
+/// let fut = pin!(pending_forget::<i32>());
+/// assert!(fut.poll(&mut cx).is_pending());
+/// ```
+pub fn pending_forget<T>() -> PendingForget<T> {
+    PendingForget {
+        _output: PhantomData,
+    }
+}
+
+/// Future which never resolves, created by [`pending_forget`].
+#[derive(Debug)]
+pub struct PendingForget<T> {
+    _output: PhantomData<fn() -> T>,
+}
+
+// SAFETY: never holds a `T`
+unsafe impl<T> Forget for PendingForget<T> {}
+
+impl<T> Future for PendingForget<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut task::Context<'_>) -> task::Poll<Self::Output> {
+        task::Poll::Pending
+    }
+}
+
+/// Creates a future which is immediately ready with a forgettable value.
+///
+/// # Examples
+///
+/// ```
+/// use std::{future::Future, pin::pin, task};
+/// use leak_playground_std::future::ready_forget;
+///
+/// let mut cx = task::Context::from_waker(task::Waker::noop());
+/// let fut = pin!(ready_forget(5));
+/// assert_eq!(fut.poll(&mut cx), task::Poll::Ready(5));
+/// ```
+pub fn ready_forget<T: Forget>(v: T) -> ReadyForget<T> {
+    ReadyForget { inner: Some(v) }
+}
+
+/// Future which is immediately ready, created by [`ready_forget`].
+///
+/// Forgettable only if `T` is.
+#[derive(Debug, Clone)]
+pub struct ReadyForget<T> {
+    inner: Option<T>,
+}
+
+impl<T> Unpin for ReadyForget<T> {}
+
+impl<T> Future for ReadyForget<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut task::Context<'_>) -> task::Poll<Self::Output> {
+        task::Poll::Ready(
+            self.inner
+                .take()
+                .expect("`ReadyForget` polled after completion"),
+        )
+    }
+}
@@ -1,8 +1,15 @@
-#![feature(auto_traits, negative_impls, thread_spawn_unchecked)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(auto_traits, negative_impls)]
+#![cfg_attr(feature = "std", feature(thread_spawn_unchecked))]
 
+#[cfg(feature = "std")]
+pub mod boxed;
 pub mod future;
 pub mod marker;
 pub mod mem;
+#[cfg(feature = "std")]
 pub mod rc;
+#[cfg(feature = "std")]
 pub mod sync;
+#[cfg(feature = "std")]
 pub mod thread;
@@ -1,6 +1,6 @@
 //! Possible [`core::marker`] additions. Contains the proposed [`Forget`] trait.
 
-use std::{fmt::Debug, future::Future, marker::PhantomData, pin::Pin, task};
+use core::{fmt::Debug, future::Future, marker::PhantomData, pin::Pin, task};
 
 /// The core trait of the destruction guarantee.
 ///
@@ -8,14 +8,204 @@ use std::{fmt::Debug, future::Future, marker::PhantomData, pin::Pin, task};
 ///
 /// Implement only if you know there's absolutely no possible way to
 /// forget your type.
+///
+/// # Examples
+///
+/// Interior mutability types like [`Mutex`](std::sync::Mutex) and
+/// [`RwLock`](std::sync::RwLock) own their value, so they are forgettable
+/// only if the value is.
+///
+/// ```
+/// use std::sync::{Mutex, RwLock};
+/// use leak_playground_std::marker::assert_forget;
+///
+/// assert_forget::<Mutex<i32>>();
+/// assert_forget::<RwLock<i32>>();
+/// ```
+///
+/// ```compile_fail
+/// use std::sync::Mutex;
+/// use leak_playground_std::{mem, thread};
+/// fn _forget_guard<'a>(thrd: thread::JoinGuard<'a, ()>) {
+///     mem::forget(Mutex::new(thrd));
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use std::sync::RwLock;
+/// use leak_playground_std::{mem, thread};
+/// fn _forget_guard<'a>(thrd: thread::JoinGuard<'a, ()>) {
+///     mem::forget(RwLock::new(thrd));
+/// }
+/// ```
+///
+/// At the same time a [`MutexGuard`](std::sync::MutexGuard) only borrows the
+/// lock, so forgetting it keeps the value locked without leaking it.
+///
+/// ```
+/// use std::sync::MutexGuard;
+/// use leak_playground_std::{marker::assert_forget, thread::JoinGuard};
+///
+/// fn _guard_of_unforgettable<'a: 'b, 'b>() {
+///     assert_forget::<MutexGuard<'b, JoinGuard<'a, ()>>>();
+/// }
+/// ```
+///
+/// Pinning pointers follow the pointer type. `Pin<&mut T>` is a borrow,
+/// so it is always forgettable, and since the pinned value still gets
+/// dropped that is fine for any `T`.
+///
+/// ```
+/// use std::pin::Pin;
+/// use leak_playground_std::{marker::assert_forget, thread::JoinGuard};
+///
+/// fn _pinned_borrow<'a: 'b, 'b>() {
+///     assert_forget::<Pin<&'b mut JoinGuard<'a, ()>>>();
+/// }
+/// assert_forget::<Pin<Box<i32>>>();
+/// ```
+///
+/// While `Pin<Box<T>>` owns the value, so it is forgettable only if `T` is.
+///
+/// ```compile_fail
+/// use leak_playground_std::{mem, thread};
+/// fn _forget_pinned<'a>(thrd: thread::JoinGuard<'a, ()>) {
+///     mem::forget(Box::pin(thrd));
+/// }
+/// ```
+///
+/// Recursive types are forgettable unless some of their fields aren't, since
+/// cycles in auto trait resolution are assumed to hold.
+///
+/// ```
+/// use leak_playground_std::{marker::assert_forget, thread::JoinGuard};
+///
+/// struct List {
+///     next: Option<Box<List>>,
+///     value: i32,
+/// }
+/// enum Tree<T> {
+///     Leaf(T),
+///     Branch(Vec<Tree<T>>),
+/// }
+/// struct Node<'a> {
+///     next: Option<Box<Node<'a>>>,
+///     guard: JoinGuard<'a, ()>,
+/// }
+///
+/// assert_forget::<List>();
+/// assert_forget::<Tree<i32>>();
+/// assert_forget::<Tree<JoinGuard<'static, ()>>>();
+/// assert_forget::<Node<'static>>();
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::{mem, thread::JoinGuard};
+/// struct Node<'a> {
+///     next: Option<Box<Node<'a>>>,
+///     guard: JoinGuard<'a, ()>,
+/// }
+/// fn _forget_node<'a>(node: Node<'a>) {
+///     mem::forget(node);
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::{mem, thread::JoinGuard};
+/// enum Tree<T> {
+///     Leaf(T),
+///     Branch(Vec<Tree<T>>),
+/// }
+/// fn _forget_tree<'a>(tree: Tree<JoinGuard<'a, ()>>) {
+///     mem::forget(tree);
+/// }
+/// ```
+///
+/// Same for mutually recursive types.
+///
+/// ```compile_fail
+/// use leak_playground_std::{mem, thread::JoinGuard};
+/// struct A<'a> {
+///     b: Option<Box<B<'a>>>,
+/// }
+/// struct B<'a> {
+///     a: Option<Box<A<'a>>>,
+///     guard: Option<JoinGuard<'a, ()>>,
+/// }
+/// fn _forget_a<'a>(a: A<'a>) {
+///     mem::forget(a);
+/// }
+/// ```
+///
+/// Trait objects hide their fields, so they are forgettable only with an
+/// explicit bound.
+///
+/// ```
+/// use leak_playground_std::marker::{assert_forget, Forget};
+///
+/// assert_forget::<Box<dyn Fn() + Forget>>();
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::marker::assert_forget;
+///
+/// assert_forget::<Box<dyn Fn()>>();
+/// ```
 pub unsafe auto trait Forget {}
 
 #[doc(inline)]
 pub use Forget as Leak;
 
+/// Compile-time witness of `T: Forget`.
+///
+/// There's no negative counterpart. Unforgettable types here are only
+/// unforgettable for some lifetimes, which trait selection ignores, so to
+/// check that use a `compile_fail` test instead.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::marker::assert_forget;
+/// assert_forget::<i32>();
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::{marker::assert_forget, thread::JoinGuard};
+/// fn _scoped_guard<'a>() {
+///     assert_forget::<JoinGuard<'a, ()>>();
+/// }
+/// ```
+pub const fn assert_forget<T: Forget + ?Sized>() {}
+
+/// Positive bound for types which are unforgettable, at least for some
+/// lifetimes, since `T: !Forget` cannot be expressed directly.
+///
+/// This is only an opt-in marker, no guarantee relies on it. For example
+/// `JoinGuard<'static, T>` is [`Forget`], while still implementing it.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::{marker::Unforgettable, thread};
+/// fn only_guards<T: Unforgettable>(_: T) {}
+///
+/// let local = 42;
+/// only_guards(thread::spawn_scoped(|| local));
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::marker::Unforgettable;
+/// fn only_guards<T: Unforgettable>(_: T) {}
+///
+/// only_guards(42);
+/// ```
+pub trait Unforgettable {}
+
+impl<T: ?Sized> Unforgettable for Unforget<'_, T> {}
+
 /// A transparent wrapper to make your types `!Forget`
 #[repr(transparent)]
-#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Clone, Copy)]
 pub struct Unforget<'a, T: ?Sized> {
     _unforget: PhantomStaticUnforget,
     /// Inner value must be able to outlive this lifetime to be able to
@@ -26,11 +216,63 @@ pub struct Unforget<'a, T: ?Sized> {
 }
 
 impl<T: ?Sized + Debug> Debug for Unforget<'_, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Unforget").field(&&self.inner).finish()
     }
 }
 
+/// Comparisons and hashing forward to the inner value, regardless of the
+/// lifetimes.
+///
+/// # Examples
+///
+/// ```
+/// use std::hash::{BuildHasher, RandomState};
+/// use leak_playground_std::marker::Unforget;
+///
+/// fn compare<'a>(x: Unforget<'a, i32>) -> bool {
+///     x == Unforget::new(42) && x < Unforget::new(43)
+/// }
+///
+/// let local = Unforget::with_lifetime(42);
+/// assert!(compare(local));
+/// let state = RandomState::new();
+/// assert_eq!(state.hash_one(Unforget::new(42)), state.hash_one(42));
+/// ```
+impl<'a, 'b, T, U> PartialEq<Unforget<'b, U>> for Unforget<'a, T>
+where
+    T: ?Sized + PartialEq<U>,
+    U: ?Sized,
+{
+    fn eq(&self, other: &Unforget<'b, U>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: ?Sized + Eq> Eq for Unforget<'_, T> {}
+
+impl<'a, 'b, T, U> PartialOrd<Unforget<'b, U>> for Unforget<'a, T>
+where
+    T: ?Sized + PartialOrd<U>,
+    U: ?Sized,
+{
+    fn partial_cmp(&self, other: &Unforget<'b, U>) -> Option<core::cmp::Ordering> {
+        self.inner.partial_cmp(&other.inner)
+    }
+}
+
+impl<T: ?Sized + Ord> Ord for Unforget<'_, T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<T: ?Sized + core::hash::Hash> core::hash::Hash for Unforget<'_, T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.inner.hash(state)
+    }
+}
+
 #[doc(inline)]
 pub use Unforget as Unleak;
 
@@ -59,13 +301,13 @@ impl<'a, T> Unforget<'a, T> {
     }
 }
 
-impl<T: ?Sized> std::ops::DerefMut for Unforget<'_, T> {
+impl<T: ?Sized> core::ops::DerefMut for Unforget<'_, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
     }
 }
 
-impl<T: ?Sized> std::ops::Deref for Unforget<'_, T> {
+impl<T: ?Sized> core::ops::Deref for Unforget<'_, T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -73,6 +315,26 @@ impl<T: ?Sized> std::ops::Deref for Unforget<'_, T> {
     }
 }
 
+/// Inner future is structurally pinned. `Unforget` is [`Unpin`] exactly when
+/// `T` is, so `Unpin` futures can be polled through a plain `&mut`.
+///
+/// # Examples
+///
+/// ```
+/// use std::{future::Future, pin::Pin, task};
+/// use leak_playground_std::marker::Unforget;
+///
+/// let mut fut = Unforget::new(std::future::ready(42));
+/// let mut cx = task::Context::from_waker(task::Waker::noop());
+/// assert_eq!(Pin::new(&mut fut).poll(&mut cx), task::Poll::Ready(42));
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::marker::Unforget;
+/// fn assert_unpin<T: Unpin>() {}
+///
+/// assert_unpin::<Unforget<'static, std::marker::PhantomPinned>>();
+/// ```
 impl<T: ?Sized> Future for Unforget<'_, T>
 where
     T: Future,
@@ -84,6 +346,82 @@ where
     }
 }
 
+/// Iterators and their adapters are forgettable only if the state they own
+/// is, including the closures.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::marker::{assert_forget, Unforget};
+///
+/// assert_forget::<std::ops::Range<i32>>();
+/// assert_forget::<std::ops::RangeInclusive<i32>>();
+/// assert_forget::<std::iter::Repeat<i32>>();
+/// assert_forget::<std::iter::Map<std::ops::Range<i32>, fn(i32) -> i32>>();
+///
+/// let it = Unforget::new(0..10);
+/// assert_forget::<Unforget<'static, std::ops::Range<i32>>>();
+/// assert_eq!(it.sum::<i32>(), 45);
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::{marker::Unforget, mem};
+///
+/// let x = 42;
+/// mem::forget(std::iter::repeat(Unforget::new(&x)));
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_std::{mem, thread::JoinGuard};
+/// fn _forget_map<'a>(thrd: JoinGuard<'a, ()>) {
+///     mem::forget((0..10).map(move |i| (i, thrd.is_finished())));
+/// }
+/// ```
+///
+/// The wrapper forwards to the inner iterator, including its size and
+/// direction:
+///
+/// ```
+/// use leak_playground_std::marker::Unforget;
+///
+/// let numbers = [1, 2, 3];
+/// let it = Unforget::<'_, _>::new(numbers.iter());
+/// assert_eq!(it.size_hint(), (3, Some(3)));
+/// assert_eq!(it.len(), 3);
+/// let mut sum = 0;
+/// for x in it {
+///     sum += x;
+/// }
+/// assert_eq!(sum, 6);
+/// let reversed: Vec<_> = Unforget::<'_, _>::new(numbers.iter()).rev().collect();
+/// assert_eq!(reversed, [&3, &2, &1]);
+/// ```
+impl<T: ?Sized> Iterator for Unforget<'_, T>
+where
+    T: Iterator,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: ?Sized> DoubleEndedIterator for Unforget<'_, T>
+where
+    T: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T: ?Sized> ExactSizeIterator for Unforget<'_, T> where T: ExactSizeIterator {}
+
 unsafe impl<'a, T: ?Sized + 'a> Forget for Unforget<'a, T> {}
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -98,8 +436,41 @@ unsafe impl<T: ?Sized> Forget for &mut T {}
 // Workaround impls since we aren't inside of std
 
 // SAFETY: it is always safe to forget JoinHandle
+#[cfg(feature = "std")]
 unsafe impl<T: 'static> Forget for std::thread::JoinHandle<T> {}
 
+/// Transparently (de)serializes the inner value. Only `'static` values can be
+/// deserialized, as there's nothing to borrow from.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::marker::Unforget;
+///
+/// let json = serde_json::to_string(&Unforget::new(42)).unwrap();
+/// assert_eq!(json, "42");
+/// let value: Unforget<'static, i32> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(*value, 42);
+/// ```
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Unforget;
+
+    impl<T: ?Sized + Serialize> Serialize for Unforget<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.inner.serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Unforget<'static, T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Unforget::new)
+        }
+    }
+}
+
 #[cfg(feature = "tokio_rt")]
 #[doc(hidden)] // Nothing to document
 mod tokio_rt {
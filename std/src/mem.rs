@@ -5,13 +5,27 @@ use core::mem;
 use crate::marker::Forget;
 
 pub fn forget<T: Forget>(x: T) {
+    trace_forget::<T>();
     mem::forget(x)
 }
 
 pub unsafe fn forget_unchecked<T>(x: T) {
+    trace_forget::<T>();
     mem::forget(x)
 }
 
+/// Prints where `T` was forgotten with the `trace-forget` feature,
+/// otherwise a no-op.
+#[inline(always)]
+fn trace_forget<T>() {
+    #[cfg(feature = "trace-forget")]
+    std::eprintln!(
+        "forgetting `{}` at:\n{}",
+        core::any::type_name::<T>(),
+        std::backtrace::Backtrace::force_capture()
+    );
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct ManuallyDrop<T: ?Sized> {
@@ -45,13 +59,40 @@ impl<T> ManuallyDrop<T> {
     }
 }
 
-impl<T: ?Sized> std::ops::DerefMut for ManuallyDrop<T> {
+impl<T: ?Sized> ManuallyDrop<T> {
+    /// Gets a pointer to the inner value, for raw projections.
+    ///
+    /// This is an associated function to not shadow methods of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::mem::ManuallyDrop;
+    ///
+    /// let mut pair = ManuallyDrop::new((1, String::from("two")));
+    /// let first = unsafe { &raw mut (*ManuallyDrop::as_mut_ptr(&mut pair)).0 };
+    /// unsafe { *first += 1 };
+    /// assert_eq!(unsafe { (*ManuallyDrop::as_ptr(&pair)).0 }, 2);
+    /// assert_eq!(ManuallyDrop::into_inner(pair), (2, String::from("two")));
+    /// ```
+    pub fn as_ptr(slot: &ManuallyDrop<T>) -> *const T {
+        &raw const *slot.inner
+    }
+
+    /// Gets a mutable pointer to the inner value, see
+    /// [`ManuallyDrop::as_ptr`].
+    pub fn as_mut_ptr(slot: &mut ManuallyDrop<T>) -> *mut T {
+        &raw mut *slot.inner
+    }
+}
+
+impl<T: ?Sized> core::ops::DerefMut for ManuallyDrop<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.inner
     }
 }
 
-impl<T: ?Sized> std::ops::Deref for ManuallyDrop<T> {
+impl<T: ?Sized> core::ops::Deref for ManuallyDrop<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -88,6 +129,11 @@ impl<T> MaybeUninit<T> {
         }
     }
 
+    /// Creates an array of uninitialized elements.
+    pub const fn uninit_array<const N: usize>() -> [Self; N] {
+        [const { Self::uninit() }; N]
+    }
+
     pub const fn zeroed() -> Self {
         MaybeUninit {
             inner: mem::MaybeUninit::uninit(),
@@ -101,6 +147,12 @@ impl<T> MaybeUninit<T> {
         self.inner.write(val)
     }
 
+    /// Sets the unforgettable value without dropping the previous one.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not take ownership over itself. The value is forgotten unless
+    /// it is later dropped, including when unwinding from a panic.
     pub unsafe fn write_unchecked(&mut self, val: T) -> &mut T {
         self.inner.write(val)
     }
@@ -117,6 +169,47 @@ impl<T> MaybeUninit<T> {
         self.inner.assume_init()
     }
 
+    /// Extracts the array of values once all of them are initialized.
+    ///
+    /// Elements may be initialized with [`MaybeUninit::write`]. For
+    /// unforgettable types prefer [`core::array::from_fn`], which drops the
+    /// already created elements if a later one panics:
+    ///
+    /// ```
+    /// use leak_playground_std::thread;
+    ///
+    /// let data = [1, 2, 3];
+    /// let data = &data;
+    /// let guards: [_; 3] = core::array::from_fn(|i| thread::spawn_scoped(move || data[i] * 2));
+    /// let outputs = guards.map(|thrd| thrd.join().unwrap());
+    /// assert_eq!(outputs, [2, 4, 6]);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Every element must be initialized. Elements written with
+    /// [`MaybeUninit::write_unchecked`] are leaked if filling the array
+    /// panics, so either nothing may panic in between, or those elements
+    /// must be dropped on unwind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::mem::MaybeUninit;
+    ///
+    /// let mut array = MaybeUninit::uninit_array::<3>();
+    /// for (i, slot) in array.iter_mut().enumerate() {
+    ///     slot.write(i * 2);
+    /// }
+    /// // SAFETY: every element has been written
+    /// let array = unsafe { MaybeUninit::array_assume_init(array) };
+    /// assert_eq!(array, [0, 2, 4]);
+    /// ```
+    pub unsafe fn array_assume_init<const N: usize>(array: [Self; N]) -> [T; N] {
+        // SAFETY: `MaybeUninit<T>` is transparent over `T`, and has no drop glue
+        (&array as *const [Self; N]).cast::<[T; N]>().read()
+    }
+
     pub const unsafe fn assume_init_read(&self) -> T {
         self.inner.assume_init_read()
     }
@@ -142,8 +235,122 @@ impl<T: Copy> Clone for MaybeUninit<T> {
     }
 }
 
-impl<T> std::fmt::Debug for MaybeUninit<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T> core::fmt::Debug for MaybeUninit<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.inner.fmt(f)
     }
 }
+
+/// Possibly initialized value, which is dropped only if it was set.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use leak_playground_std::mem::InitGuard;
+///
+/// struct Counted<'a>(&'a Cell<usize>);
+///
+/// impl Drop for Counted<'_> {
+///     fn drop(&mut self) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let drops = Cell::new(0);
+/// assert!(InitGuard::<Counted>::new().into_inner().is_none());
+///
+/// let mut slot = InitGuard::new();
+/// slot.set(Counted(&drops));
+/// slot.set(Counted(&drops));
+/// assert_eq!(drops.get(), 1);
+/// let value = slot.into_inner().unwrap();
+/// assert_eq!(drops.get(), 1);
+/// drop(value);
+/// assert_eq!(drops.get(), 2);
+///
+/// let mut slot = InitGuard::new();
+/// slot.set(Counted(&drops));
+/// drop(slot);
+/// assert_eq!(drops.get(), 3);
+/// ```
+pub struct InitGuard<T> {
+    value: mem::MaybeUninit<T>,
+    init: bool,
+}
+
+impl<T> InitGuard<T> {
+    pub const fn new() -> Self {
+        InitGuard {
+            value: mem::MaybeUninit::uninit(),
+            init: false,
+        }
+    }
+
+    /// Sets the value, dropping the previous one.
+    pub fn set(&mut self, val: T) -> &mut T
+    where
+        T: Forget,
+    {
+        // SAFETY: `T: Forget`
+        unsafe { self.set_unchecked(val) }
+    }
+
+    /// Sets the unforgettable value, dropping the previous one.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not take ownership over itself.
+    pub unsafe fn set_unchecked(&mut self, val: T) -> &mut T {
+        self.clear();
+        self.init = true;
+        self.value.write(val)
+    }
+
+    /// Drops the value if it was set.
+    pub fn clear(&mut self) {
+        if self.init {
+            self.init = false;
+            // SAFETY: value was initialized
+            unsafe { self.value.assume_init_drop() }
+        }
+    }
+
+    pub fn is_init(&self) -> bool {
+        self.init
+    }
+
+    pub fn get(&self) -> Option<&T> {
+        // SAFETY: value is initialized
+        self.init.then(|| unsafe { self.value.assume_init_ref() })
+    }
+
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: value is initialized
+        self.init.then(|| unsafe { self.value.assume_init_mut() })
+    }
+
+    pub fn into_inner(self) -> Option<T> {
+        let this = mem::ManuallyDrop::new(self);
+        // SAFETY: value is initialized and `this` won't drop it
+        this.init.then(|| unsafe { this.value.assume_init_read() })
+    }
+}
+
+impl<T> Default for InitGuard<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for InitGuard<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for InitGuard<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("InitGuard").field(&self.get()).finish()
+    }
+}
@@ -1,11 +1,11 @@
 //! Possible [`std::rc`] replacements.
 
 use core::fmt;
-use std::rc::Rc as StdRc;
+use std::rc::{Rc as StdRc, Weak as StdWeak};
 
 use crate::marker::Forget;
 
-#[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Rc<T> {
     inner: StdRc<T>,
 }
@@ -31,6 +31,101 @@ impl<T> Rc<T> {
             inner: StdRc::new(x),
         }
     }
+
+    /// Provides a raw pointer to the data, which is the same for all clones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::rc::Rc;
+    ///
+    /// let a = Rc::new(42);
+    /// let b = Rc::clone(&a);
+    /// assert_eq!(Rc::as_ptr(&a), Rc::as_ptr(&b));
+    /// assert_ne!(Rc::as_ptr(&a), Rc::as_ptr(&Rc::new(42)));
+    /// ```
+    pub fn as_ptr(this: &Self) -> *const T {
+        StdRc::as_ptr(&this.inner)
+    }
+
+    /// Constructs a new `Rc<T>` while giving out a [`Weak`] pointer to it,
+    /// which can only be upgraded once the construction is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::rc::{Rc, Weak};
+    ///
+    /// struct Node {
+    ///     me: Weak<Node>,
+    /// }
+    ///
+    /// let node = Rc::new_cyclic(|me| {
+    ///     assert!(me.upgrade().is_none());
+    ///     Node { me: me.clone() }
+    /// });
+    /// let me = node.me.upgrade().unwrap();
+    /// assert_eq!(Rc::as_ptr(&me), Rc::as_ptr(&node));
+    /// drop(me);
+    ///
+    /// let weak = Rc::downgrade(&node);
+    /// drop(node);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn new_cyclic<F>(data_fn: F) -> Self
+    where
+        F: FnOnce(&Weak<T>) -> T,
+        T: Forget,
+    {
+        Rc {
+            inner: StdRc::new_cyclic(|weak| {
+                data_fn(&Weak {
+                    inner: weak.clone(),
+                })
+            }),
+        }
+    }
+
+    /// Creates a new [`Weak`] pointer to this allocation.
+    pub fn downgrade(this: &Self) -> Weak<T> {
+        Weak {
+            inner: StdRc::downgrade(&this.inner),
+        }
+    }
+
+    /// Gets the number of strong pointers to this allocation.
+    pub fn strong_count(this: &Self) -> usize {
+        StdRc::strong_count(&this.inner)
+    }
+
+    /// Gets the number of weak pointers to this allocation.
+    pub fn weak_count(this: &Self) -> usize {
+        StdRc::weak_count(&this.inner)
+    }
+}
+
+/// Requires `T: Forget` just like [`Rc::new`].
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::rc::Rc;
+///
+/// assert_eq!(*Rc::<i32>::default(), 0);
+/// ```
+///
+/// ```compile_fail
+/// use std::marker::PhantomData;
+/// use leak_playground_std::{marker::Unforget, rc::Rc};
+///
+/// fn _unforgettable<'a>() {
+///     Rc::<Unforget<'static, PhantomData<&'a ()>>>::default();
+/// }
+/// ```
+impl<T: Default + Forget> Default for Rc<T> {
+    fn default() -> Self {
+        Rc::new(T::default())
+    }
 }
 
 impl<T> Clone for Rc<T> {
@@ -70,12 +165,32 @@ where
     }
 }
 
+/// The alternate form also shows reference counts.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::rc::Rc;
+///
+/// let a = Rc::new(42);
+/// let b = Rc::clone(&a);
+/// assert_eq!(format!("{b:?}"), "42");
+/// assert!(format!("{b:#?}").contains("strong: 2"));
+/// ```
 impl<T> fmt::Debug for Rc<T>
 where
     T: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(&self.inner, f)
+        if f.alternate() {
+            f.debug_struct("Rc")
+                .field("strong", &Rc::strong_count(self))
+                .field("weak", &Rc::weak_count(self))
+                .field("value", &*self.inner)
+                .finish()
+        } else {
+            fmt::Debug::fmt(&self.inner, f)
+        }
     }
 }
 
@@ -84,3 +199,52 @@ impl<T> fmt::Pointer for Rc<T> {
         fmt::Pointer::fmt(&self.inner, f)
     }
 }
+
+/// Non-owning pointer to a [`Rc`] allocation.
+pub struct Weak<T> {
+    inner: StdWeak<T>,
+}
+
+impl<T> Weak<T> {
+    /// Constructs a `Weak<T>` without an allocation, which never upgrades.
+    pub const fn new() -> Self {
+        Weak {
+            inner: StdWeak::new(),
+        }
+    }
+
+    /// Gets a strong pointer, if the value hasn't been dropped yet.
+    pub fn upgrade(&self) -> Option<Rc<T>> {
+        self.inner.upgrade().map(|inner| Rc { inner })
+    }
+
+    /// Gets the number of strong pointers to this allocation.
+    pub fn strong_count(&self) -> usize {
+        self.inner.strong_count()
+    }
+
+    /// Gets the number of weak pointers to this allocation.
+    pub fn weak_count(&self) -> usize {
+        self.inner.weak_count()
+    }
+}
+
+impl<T> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        Weak {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Default for Weak<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Weak<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
@@ -1,6 +1,7 @@
 //! Possible [`std::sync`] additions and replacements.
 
 mod arc;
+mod shared_arc;
 
 pub mod mpsc {
     use std::sync::mpsc;
@@ -31,3 +32,4 @@ pub mod mpsc {
 }
 
 pub use arc::*;
+pub use shared_arc::*;
@@ -1,11 +1,11 @@
 //! Possible `Rc` implementation
 
 use core::fmt;
-use std::sync::Arc as StdArc;
+use std::sync::{Arc as StdArc, Weak as StdWeak};
 
 use crate::marker::Forget;
 
-#[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Arc<T> {
     inner: StdArc<T>,
 }
@@ -31,6 +31,134 @@ impl<T> Arc<T> {
             inner: StdArc::new(x),
         }
     }
+
+    /// Returns the inner value if this is the only `Arc`, otherwise clones
+    /// it out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::sync::Arc;
+    ///
+    /// let a = Arc::new(String::from("hello"));
+    /// let b = Arc::clone(&a);
+    /// assert_eq!(Arc::unwrap_or_clone(a), "hello");
+    /// assert_eq!(Arc::unwrap_or_clone(b), "hello");
+    /// ```
+    pub fn unwrap_or_clone(this: Self) -> T
+    where
+        T: Clone + Forget,
+    {
+        StdArc::unwrap_or_clone(this.inner)
+    }
+
+    pub(super) fn into_raw(this: Self) -> *const T {
+        StdArc::into_raw(this.inner)
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must come from [`Arc::into_raw`] and have an owned strong count.
+    pub(super) unsafe fn from_raw(ptr: *const T) -> Self {
+        Arc {
+            inner: StdArc::from_raw(ptr),
+        }
+    }
+
+    /// Provides a raw pointer to the data, which is the same for all clones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::sync::Arc;
+    ///
+    /// let a = Arc::new(42);
+    /// let b = Arc::clone(&a);
+    /// assert_eq!(Arc::as_ptr(&a), Arc::as_ptr(&b));
+    /// assert_ne!(Arc::as_ptr(&a), Arc::as_ptr(&Arc::new(42)));
+    /// ```
+    pub fn as_ptr(this: &Self) -> *const T {
+        StdArc::as_ptr(&this.inner)
+    }
+
+    /// Constructs a new `Arc<T>` while giving out a [`Weak`] pointer to it,
+    /// which can only be upgraded once the construction is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::sync::{Arc, Weak};
+    ///
+    /// struct Node {
+    ///     me: Weak<Node>,
+    /// }
+    ///
+    /// let node = Arc::new_cyclic(|me| {
+    ///     assert!(me.upgrade().is_none());
+    ///     Node { me: me.clone() }
+    /// });
+    /// let me = node.me.upgrade().unwrap();
+    /// assert_eq!(Arc::as_ptr(&me), Arc::as_ptr(&node));
+    /// drop(me);
+    ///
+    /// let weak = Arc::downgrade(&node);
+    /// drop(node);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn new_cyclic<F>(data_fn: F) -> Self
+    where
+        F: FnOnce(&Weak<T>) -> T,
+        T: Forget,
+    {
+        Arc {
+            inner: StdArc::new_cyclic(|weak| {
+                data_fn(&Weak {
+                    inner: weak.clone(),
+                })
+            }),
+        }
+    }
+
+    /// Creates a new [`Weak`] pointer to this allocation.
+    pub fn downgrade(this: &Self) -> Weak<T> {
+        Weak {
+            inner: StdArc::downgrade(&this.inner),
+        }
+    }
+
+    /// Gets the number of strong pointers to this allocation.
+    pub fn strong_count(this: &Self) -> usize {
+        StdArc::strong_count(&this.inner)
+    }
+
+    /// Gets the number of weak pointers to this allocation.
+    pub fn weak_count(this: &Self) -> usize {
+        StdArc::weak_count(&this.inner)
+    }
+}
+
+/// Requires `T: Forget` just like [`Arc::new`].
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::sync::Arc;
+///
+/// assert_eq!(*Arc::<i32>::default(), 0);
+/// ```
+///
+/// ```compile_fail
+/// use std::marker::PhantomData;
+/// use leak_playground_std::{marker::Unforget, sync::Arc};
+///
+/// fn _unforgettable<'a>() {
+///     Arc::<Unforget<'static, PhantomData<&'a ()>>>::default();
+/// }
+/// ```
+impl<T: Default + Forget> Default for Arc<T> {
+    fn default() -> Self {
+        Arc::new(T::default())
+    }
 }
 
 impl<T> Clone for Arc<T> {
@@ -70,12 +198,32 @@ where
     }
 }
 
+/// The alternate form also shows reference counts.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::sync::Arc;
+///
+/// let a = Arc::new(42);
+/// let b = Arc::clone(&a);
+/// assert_eq!(format!("{b:?}"), "42");
+/// assert!(format!("{b:#?}").contains("strong: 2"));
+/// ```
 impl<T> fmt::Debug for Arc<T>
 where
     T: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(&self.inner, f)
+        if f.alternate() {
+            f.debug_struct("Arc")
+                .field("strong", &Arc::strong_count(self))
+                .field("weak", &Arc::weak_count(self))
+                .field("value", &*self.inner)
+                .finish()
+        } else {
+            fmt::Debug::fmt(&self.inner, f)
+        }
     }
 }
 
@@ -84,3 +232,52 @@ impl<T> fmt::Pointer for Arc<T> {
         fmt::Pointer::fmt(&self.inner, f)
     }
 }
+
+/// Non-owning pointer to a [`Arc`] allocation.
+pub struct Weak<T> {
+    inner: StdWeak<T>,
+}
+
+impl<T> Weak<T> {
+    /// Constructs a `Weak<T>` without an allocation, which never upgrades.
+    pub const fn new() -> Self {
+        Weak {
+            inner: StdWeak::new(),
+        }
+    }
+
+    /// Gets a strong pointer, if the value hasn't been dropped yet.
+    pub fn upgrade(&self) -> Option<Arc<T>> {
+        self.inner.upgrade().map(|inner| Arc { inner })
+    }
+
+    /// Gets the number of strong pointers to this allocation.
+    pub fn strong_count(&self) -> usize {
+        self.inner.strong_count()
+    }
+
+    /// Gets the number of weak pointers to this allocation.
+    pub fn weak_count(&self) -> usize {
+        self.inner.weak_count()
+    }
+}
+
+impl<T> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        Weak {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Default for Weak<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Weak<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
@@ -0,0 +1,134 @@
+//! Atomically replaceable `Arc`
+
+use core::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::Arc as StdArc;
+
+use super::Arc;
+
+/// Shared slot holding an [`Arc`], which can be loaded and replaced from
+/// multiple threads without locking.
+///
+/// Loading never blocks. Replacing waits until concurrent loads are done with
+/// the old pointer, so constant loading may delay replacing indefinitely.
+///
+/// Replaced values are dropped as usual, thus it is fine to hold
+/// unforgettable values created with [`Arc::new_unchecked`].
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::sync::{Arc, SharedArc};
+/// use leak_playground_std::thread;
+///
+/// struct Config {
+///     version: u32,
+/// }
+///
+/// let config = SharedArc::new(Arc::new(Config { version: 1 }));
+/// let reader = thread::spawn_scoped(|| {
+///     let mut last = 0;
+///     while last < 2 {
+///         let version = config.load().version;
+///         assert!(version >= last);
+///         last = version;
+///     }
+/// });
+/// config.store(Arc::new(Config { version: 2 }));
+/// reader.join().unwrap();
+///
+/// let stale = Arc::new(Config { version: 0 });
+/// let rejected = config.compare_and_swap(&stale, Arc::new(Config { version: 3 }));
+/// assert_eq!(rejected.err().unwrap().version, 3);
+/// let current = config.load();
+/// let old = config.compare_and_swap(&current, Arc::new(Config { version: 3 }));
+/// assert_eq!(old.ok().unwrap().version, 2);
+/// assert_eq!(config.load().version, 3);
+/// ```
+pub struct SharedArc<T> {
+    ptr: AtomicPtr<T>,
+    /// Number of loads in progress
+    readers: AtomicUsize,
+    _owns: PhantomData<Arc<T>>,
+}
+
+impl<T> SharedArc<T> {
+    pub fn new(value: Arc<T>) -> Self {
+        SharedArc {
+            ptr: AtomicPtr::new(Arc::into_raw(value).cast_mut()),
+            readers: AtomicUsize::new(0),
+            _owns: PhantomData,
+        }
+    }
+
+    /// Returns the current value.
+    pub fn load(&self) -> Arc<T> {
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        let ptr = self.ptr.load(Ordering::SeqCst);
+        // SAFETY: `swap` won't release the pointer until we are done
+        unsafe { StdArc::increment_strong_count(ptr) };
+        self.readers.fetch_sub(1, Ordering::SeqCst);
+        // SAFETY: we have just incremented the strong count
+        unsafe { Arc::from_raw(ptr) }
+    }
+
+    /// Replaces the value, dropping the old one.
+    pub fn store(&self, value: Arc<T>) {
+        drop(self.swap(value));
+    }
+
+    /// Replaces the value, returning the old one.
+    pub fn swap(&self, value: Arc<T>) -> Arc<T> {
+        let old = self
+            .ptr
+            .swap(Arc::into_raw(value).cast_mut(), Ordering::SeqCst);
+        self.wait_for_readers();
+        // SAFETY: strong count of the old value is ours now
+        unsafe { Arc::from_raw(old) }
+    }
+
+    /// Replaces the value with `new` if it is still `current`, comparing by
+    /// pointer.
+    ///
+    /// Returns the old value on success, otherwise gives `new` back.
+    pub fn compare_and_swap(&self, current: &Arc<T>, new: Arc<T>) -> Result<Arc<T>, Arc<T>> {
+        let new = Arc::into_raw(new).cast_mut();
+        match self.ptr.compare_exchange(
+            Arc::as_ptr(current).cast_mut(),
+            new,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(old) => {
+                self.wait_for_readers();
+                // SAFETY: strong count of the old value is ours now
+                Ok(unsafe { Arc::from_raw(old) })
+            }
+            // SAFETY: `new` hasn't been published
+            Err(_) => Err(unsafe { Arc::from_raw(new) }),
+        }
+    }
+
+    fn wait_for_readers(&self) {
+        while self.readers.load(Ordering::SeqCst) != 0 {
+            std::hint::spin_loop();
+        }
+    }
+}
+
+impl<T> Drop for SharedArc<T> {
+    fn drop(&mut self) {
+        // SAFETY: there are no loads in progress since we have `&mut self`
+        drop(unsafe { Arc::from_raw(*self.ptr.get_mut()) });
+    }
+}
+
+impl<T> fmt::Debug for SharedArc<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedArc").field(&self.load()).finish()
+    }
+}
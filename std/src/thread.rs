@@ -12,31 +12,390 @@
 //! });
 //! tx.send(thrd).unwrap();
 //! ```
+//!
+//! The thread may drop its own guard, which detaches the thread instead of
+//! joining on itself
+//!
+//! ```
+//! use leak_playground_std::*;
+//! let (tx, rx) = sync::mpsc::rendezvous_channel();
+//! let (done_tx, done_rx) = std::sync::mpsc::channel();
+//! let thrd = thread::spawn_scoped(move || {
+//!     let this_thread = rx.recv().unwrap();
+//!     drop(this_thread);
+//!     done_tx.send(()).unwrap();
+//! });
+//! tx.send(thrd).unwrap();
+//! done_rx.recv().unwrap();
+//! ```
 
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::task::{self, Poll};
 use std::thread::JoinHandle;
 use std::{marker::PhantomData, thread};
 
-use crate::marker::{Forget, Unforget};
+use crate::marker::{Forget, Unforget, Unforgettable};
 use crate::mem::{self, ManuallyDrop};
 use crate::rc::Rc;
 use crate::sync::Arc;
 
+pub use std::thread::{current, park, park_timeout, sleep, yield_now, Thread, ThreadId};
+
 /// Spawn borrowing thread handles.
 pub fn spawn_scoped<'a, F, T>(f: F) -> JoinGuard<'a, T>
 where
     F: FnOnce() -> T + Send + 'a,
     T: Send + 'a,
 {
+    let depth = current_guard_depth() + 1;
+    let completion = std::sync::Arc::new(Completion::default());
+    let f = {
+        let completion = completion.clone();
+        move || {
+            // Signals even if `f` panics
+            let _signal = CompletionSignal(completion);
+            GUARD_DEPTH.with(|d| d.set(depth));
+            f()
+        }
+    };
     JoinGuard {
         // SAFETY: destruction guarantee from `Unforget<&'a ()>` and `T: 'a`
         child: unsafe {
             ManuallyDrop::new_unchecked(thread::Builder::new().spawn_unchecked(f).unwrap())
         },
+        cancel: None,
+        completion: Some(completion),
         _borrow: Unforget::new(PhantomData),
         _unsend: PhantomData,
     }
 }
 
+/// Spawn borrowing thread handles, passing a [`CancelToken`] to the thread.
+///
+/// The token is cancelled when the guard is dropped, before joining the
+/// thread. It can also be cancelled earlier via the returned token.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::thread;
+///
+/// let (thrd, token) = thread::spawn_scoped_cancellable(|token| {
+///     while !token.is_cancelled() {
+///         thread::yield_now();
+///     }
+/// });
+/// token.cancel();
+/// thrd.join().unwrap();
+/// ```
+///
+/// Dropping the guard cancels the thread:
+///
+/// ```
+/// use leak_playground_std::thread;
+///
+/// let (thrd, token) = thread::spawn_scoped_cancellable(|token| {
+///     while !token.is_cancelled() {
+///         thread::yield_now();
+///     }
+/// });
+/// drop(thrd);
+/// assert!(token.is_cancelled());
+/// ```
+pub fn spawn_scoped_cancellable<'a, F, T>(f: F) -> (JoinGuard<'a, T>, CancelToken)
+where
+    F: FnOnce(&CancelToken) -> T + Send + 'a,
+    T: Send + 'a,
+{
+    let token = CancelToken::new();
+    let mut thrd = spawn_scoped({
+        let token = token.clone();
+        move || f(&token)
+    });
+    thrd.cancel = Some(token.clone());
+    (thrd, token)
+}
+
+/// Cooperative cancellation flag shared between clones.
+#[derive(Debug, Default, Clone)]
+pub struct CancelToken {
+    cancelled: std::sync::Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the cancellation.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// Spawn borrowing thread handles, also returning a [`JoinHandleRef`] which
+/// can be shared with other threads.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::thread;
+///
+/// let local = 42;
+/// let (thrd, handle) = thread::spawn_scoped_handle(|| {
+///     thread::park();
+///     local + 1
+/// });
+/// let observer = std::thread::spawn(move || {
+///     handle.unpark();
+///     while !handle.is_finished() {
+///         thread::yield_now();
+///     }
+/// });
+/// observer.join().unwrap();
+/// assert_eq!(thrd.join().unwrap(), 43);
+/// ```
+pub fn spawn_scoped_handle<'a, F, T>(f: F) -> (JoinGuard<'a, T>, JoinHandleRef)
+where
+    F: FnOnce() -> T + Send + 'a,
+    T: Send + 'a,
+{
+    let thrd = spawn_scoped(f);
+    let handle = JoinHandleRef {
+        thread: thrd.thread().clone(),
+        completion: thrd
+            .completion
+            .clone()
+            .expect("spawned threads signal completion"),
+    };
+    (thrd, handle)
+}
+
+/// Observes a thread without owning it, see [`spawn_scoped_handle`].
+///
+/// Unlike [`JoinGuard`] it is always `Send`, as it doesn't join the thread.
+#[derive(Clone)]
+pub struct JoinHandleRef {
+    thread: thread::Thread,
+    completion: std::sync::Arc<Completion>,
+}
+
+impl JoinHandleRef {
+    pub fn thread(&self) -> &thread::Thread {
+        &self.thread
+    }
+
+    /// Whether the thread's closure has returned or panicked.
+    pub fn is_finished(&self) -> bool {
+        self.completion.is_finished()
+    }
+
+    pub fn unpark(&self) {
+        self.thread.unpark()
+    }
+}
+
+impl std::fmt::Debug for JoinHandleRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JoinHandleRef")
+            .field("thread", &self.thread)
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+/// Set by the thread once its closure returns or unwinds.
+#[derive(Default)]
+struct Completion {
+    state: Mutex<CompletionState>,
+    cond: Condvar,
+}
+
+#[derive(Default)]
+struct CompletionState {
+    finished: bool,
+    /// Keyed by [`CompletionFuture`], which removes its waker on drop
+    wakers: HashMap<u64, task::Waker>,
+    next_key: u64,
+}
+
+impl Completion {
+    fn lock(&self) -> MutexGuard<'_, CompletionState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.lock().finished
+    }
+
+    fn wait(&self) {
+        drop(
+            self.cond
+                .wait_while(self.lock(), |state| !state.finished)
+                .unwrap_or_else(|e| e.into_inner()),
+        );
+    }
+
+    fn poll(&self, key: &mut Option<u64>, cx: &mut task::Context<'_>) -> Poll<()> {
+        let mut state = self.lock();
+        if state.finished {
+            return Poll::Ready(());
+        }
+        let key = *key.get_or_insert_with(|| {
+            state.next_key += 1;
+            state.next_key
+        });
+        match state.wakers.get_mut(&key) {
+            Some(waker) if waker.will_wake(cx.waker()) => (),
+            Some(waker) => waker.clone_from(cx.waker()),
+            None => {
+                state.wakers.insert(key, cx.waker().clone());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+struct CompletionSignal(std::sync::Arc<Completion>);
+
+impl Drop for CompletionSignal {
+    fn drop(&mut self) {
+        let wakers = {
+            let mut state = self.0.lock();
+            state.finished = true;
+            std::mem::take(&mut state.wakers)
+        };
+        self.0.cond.notify_all();
+        wakers.into_values().for_each(task::Waker::wake);
+    }
+}
+
+/// Future of [`JoinGuard::completion`].
+pub struct CompletionFuture<'a> {
+    completion: &'a Completion,
+    /// Slot of this future's waker, once it was polled
+    key: Option<u64>,
+}
+
+impl std::future::Future for CompletionFuture<'_> {
+    type Output = ();
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        this.completion.poll(&mut this.key, cx)
+    }
+}
+
+impl Drop for CompletionFuture<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.completion.lock().wakers.remove(&key);
+        }
+    }
+}
+
+thread_local! {
+    static GUARD_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Returns how many scoped threads the current thread is nested in.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::thread;
+///
+/// assert_eq!(thread::current_guard_depth(), 0);
+/// let thrd = thread::spawn_scoped(|| {
+///     assert_eq!(thread::current_guard_depth(), 1);
+///     thread::spawn_scoped(thread::current_guard_depth).join().unwrap()
+/// });
+/// assert_eq!(thrd.join().unwrap(), 2);
+/// ```
+pub fn current_guard_depth() -> usize {
+    GUARD_DEPTH.with(Cell::get)
+}
+
+/// Estimates how many threads are worth spawning, like
+/// [`std::thread::available_parallelism`], falling back to one if unknown.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::thread;
+///
+/// let n = thread::effective_parallelism().get();
+/// assert!(n >= 1);
+/// ```
+pub fn effective_parallelism() -> std::num::NonZeroUsize {
+    thread::available_parallelism().unwrap_or(std::num::NonZeroUsize::MIN)
+}
+
+/// Spawn borrowing thread handles, pinning the thread to the CPU `core`.
+///
+/// Pinning is only done with the `affinity` feature on Linux, Android and
+/// Windows. Otherwise, or if `core` is out of range, it is a no-op.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::thread;
+/// let local = 42;
+/// let thrd = thread::spawn_scoped_affinity(0, || local + 1);
+/// assert_eq!(thrd.join().unwrap(), 43);
+/// ```
+pub fn spawn_scoped_affinity<'a, F, T>(core: usize, f: F) -> JoinGuard<'a, T>
+where
+    F: FnOnce() -> T + Send + 'a,
+    T: Send + 'a,
+{
+    spawn_scoped(move || {
+        set_affinity(core);
+        f()
+    })
+}
+
+#[cfg(all(feature = "affinity", any(target_os = "linux", target_os = "android")))]
+fn set_affinity(core: usize) {
+    if core >= libc::CPU_SETSIZE as usize {
+        return;
+    }
+    // SAFETY: `cpu_set_t` is a plain bitset and `core` is within its bounds
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(core, &mut set);
+        // Affinity is only a hint, thus ignoring errors
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set);
+    }
+}
+
+#[cfg(all(feature = "affinity", windows))]
+fn set_affinity(core: usize) {
+    use windows_sys::Win32::System::Threading::{GetCurrentThread, SetThreadAffinityMask};
+
+    let Some(mask) = u32::try_from(core).ok().and_then(|c| 1usize.checked_shl(c)) else {
+        return;
+    };
+    // SAFETY: pseudo handle of the current thread is always valid
+    unsafe {
+        // Affinity is only a hint, thus ignoring errors
+        SetThreadAffinityMask(GetCurrentThread(), mask);
+    }
+}
+
+#[cfg(not(all(
+    feature = "affinity",
+    any(target_os = "linux", target_os = "android", windows)
+)))]
+fn set_affinity(_core: usize) {}
+
 /// Handle to a thread, which joins on drop.
 ///
 /// Cannot be sent across threads.
@@ -45,6 +404,10 @@ where
 /// To spawn use [`spawn_scoped`].
 pub struct JoinGuard<'a, T> {
     child: ManuallyDrop<thread::JoinHandle<T>>,
+    /// Cancelled on drop, see [`spawn_scoped_cancellable`].
+    cancel: Option<CancelToken>,
+    /// Missing for adopted threads, see [`JoinGuard::from_handle_unchecked`].
+    completion: Option<std::sync::Arc<Completion>>,
 
     /// Not sure about covariance there.
     _borrow: Unforget<'static, PhantomData<&'a ()>>,
@@ -54,18 +417,144 @@ pub struct JoinGuard<'a, T> {
 unsafe impl<T> Send for JoinGuard<'_, T> where Self: Forget {}
 unsafe impl<T> Sync for JoinGuard<'_, T> {}
 
-impl<T> JoinGuard<'_, T> {
-    pub fn join(mut self) -> std::thread::Result<T> {
-        let join_handle;
-        // SAFETY: we immediately, join after
-        unsafe {
-            join_handle = ManuallyDrop::take(&mut self.child);
-            // need this to avoid calling `JoinGuard::drop`
-            mem::forget_unchecked(self);
+impl<'a, T> JoinGuard<'a, T> {
+    /// Adopts a thread spawned elsewhere, making it join on drop.
+    ///
+    /// Inverse of `From<JoinGuard<'static, T>> for JoinHandle<T>`.
+    ///
+    /// # Safety
+    ///
+    /// Everything borrowed by the thread must outlive `'a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::thread::JoinGuard;
+    ///
+    /// let local = 42;
+    /// let local_ref: &'static i32 = unsafe { &*(&local as *const i32) };
+    /// let handle = std::thread::spawn(move || *local_ref + 1);
+    /// // SAFETY: `local` outlives the guard
+    /// let thrd = unsafe { JoinGuard::from_handle_unchecked(handle) };
+    /// assert_eq!(thrd.join().unwrap(), 43);
+    /// ```
+    pub unsafe fn from_handle_unchecked(handle: JoinHandle<T>) -> Self {
+        JoinGuard {
+            child: ManuallyDrop::new_unchecked(handle),
+            cancel: None,
+            completion: None,
+            _borrow: Unforget::new(PhantomData),
+            _unsend: PhantomData,
         }
+    }
+
+    pub fn join(self) -> std::thread::Result<T> {
+        // SAFETY: we immediately, join after
+        let join_handle = unsafe { self.take_child() };
         join_handle.join()
     }
 
+    /// Joins the thread, boxing its output to handle outputs of different
+    /// types together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::thread;
+    ///
+    /// let local = 42;
+    /// let number = thread::spawn_scoped(|| local + 1);
+    /// let text = thread::spawn_scoped(|| local.to_string());
+    /// let outputs = [number.join_boxed(), text.join_boxed()].map(Result::unwrap);
+    /// let [number, text] = outputs;
+    /// assert_eq!(*number.downcast::<i32>().unwrap(), 43);
+    /// assert_eq!(*text.downcast::<String>().unwrap(), "42");
+    /// ```
+    pub fn join_boxed(self) -> std::thread::Result<Box<dyn std::any::Any + Send>>
+    where
+        T: Send + 'static,
+    {
+        self.join()
+            .map(|output| Box::new(output) as Box<dyn std::any::Any + Send>)
+    }
+
+    /// Detaches the thread without joining, for emergency teardown where
+    /// joining could block.
+    ///
+    /// The thread keeps running. Its [`CancelToken`], if any, is not
+    /// cancelled.
+    ///
+    /// # Safety
+    ///
+    /// The thread must not use anything borrowed for `'a` after the borrow
+    /// ends, as if the guard was forgotten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::thread;
+    ///
+    /// let (tx, rx) = std::sync::mpsc::channel::<()>();
+    /// let (thrd, handle) = thread::spawn_scoped_handle(move || rx.recv().unwrap());
+    /// // SAFETY: the thread doesn't borrow anything
+    /// unsafe { thrd.leak() };
+    /// assert!(!handle.is_finished());
+    /// tx.send(()).unwrap();
+    /// while !handle.is_finished() {
+    ///     thread::yield_now();
+    /// }
+    /// ```
+    pub unsafe fn leak(self) {
+        drop(self.take_child());
+    }
+
+    /// Takes out the join handle without joining it.
+    ///
+    /// # Safety
+    ///
+    /// The thread must be joined unless `Self: Forget`.
+    unsafe fn take_child(mut self) -> JoinHandle<T> {
+        let join_handle = ManuallyDrop::take(&mut self.child);
+        drop(self.cancel.take());
+        drop(self.completion.take());
+        // need this to avoid calling `JoinGuard::drop`
+        mem::forget_unchecked(self);
+        join_handle
+    }
+
+    /// Joins the thread only if it has already finished, otherwise gives the
+    /// guard back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::thread;
+    ///
+    /// let (tx, rx) = std::sync::mpsc::channel();
+    /// let thrd = thread::spawn_scoped(move || {
+    ///     rx.recv().unwrap();
+    ///     42
+    /// });
+    /// let Err(thrd) = thrd.try_join() else {
+    ///     panic!("thread has finished before it was told to");
+    /// };
+    /// tx.send(()).unwrap();
+    /// while !thrd.is_finished() {
+    ///     thread::yield_now();
+    /// }
+    /// let Ok(res) = thrd.try_join() else {
+    ///     panic!("thread has not finished");
+    /// };
+    /// assert_eq!(res.unwrap(), 42);
+    /// ```
+    pub fn try_join(self) -> Result<std::thread::Result<T>, Self> {
+        if self.is_finished() {
+            Ok(self.join())
+        } else {
+            Err(self)
+        }
+    }
+
     pub fn thread(&self) -> &std::thread::Thread {
         self.child.thread()
     }
@@ -74,6 +563,83 @@ impl<T> JoinGuard<'_, T> {
         self.child.is_finished()
     }
 
+    /// Blocks until the thread's closure has returned or panicked, without
+    /// taking the guard. Joining afterwards doesn't block for long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use leak_playground_std::{sync::Arc, thread};
+    ///
+    /// static DONE: AtomicBool = AtomicBool::new(false);
+    /// let (tx, rx) = std::sync::mpsc::channel();
+    /// let thrd = thread::spawn_scoped(move || {
+    ///     rx.recv().unwrap();
+    ///     DONE.store(true, Ordering::Relaxed);
+    /// })
+    /// .into_arc();
+    /// let other = Arc::clone(&thrd);
+    /// let waiter = std::thread::spawn(move || {
+    ///     other.wait_finished();
+    ///     assert!(DONE.load(Ordering::Relaxed));
+    /// });
+    /// tx.send(()).unwrap();
+    /// thrd.wait_finished();
+    /// assert!(DONE.load(Ordering::Relaxed));
+    /// waiter.join().unwrap();
+    /// ```
+    pub fn wait_finished(&self) {
+        let Some(completion) = &self.completion else {
+            // Adopted threads don't signal, falling back to polling
+            while !self.is_finished() {
+                thread::sleep(std::time::Duration::from_millis(1));
+            }
+            return;
+        };
+        completion.wait();
+    }
+
+    /// Resolves once the thread's closure has returned or panicked, like
+    /// [`JoinGuard::wait_finished`] but asynchronously.
+    ///
+    /// Returns `None` for threads adopted with
+    /// [`JoinGuard::from_handle_unchecked`], since those cannot notify about
+    /// their completion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::{sync::Arc, thread};
+    ///
+    /// let rt = tokio::runtime::Runtime::new().unwrap();
+    /// let (tx, rx) = std::sync::mpsc::channel::<()>();
+    /// let thrd = thread::spawn_scoped(move || rx.recv().unwrap()).into_arc();
+    /// let waiters: Vec<_> = (0..2)
+    ///     .map(|_| {
+    ///         let thrd = Arc::clone(&thrd);
+    ///         rt.spawn(async move { thrd.completion().unwrap().await })
+    ///     })
+    ///     .collect();
+    /// tx.send(()).unwrap();
+    /// rt.block_on(async {
+    ///     for waiter in waiters {
+    ///         waiter.await.unwrap();
+    ///     }
+    /// });
+    ///
+    /// let adopted = unsafe { thread::JoinGuard::from_handle_unchecked(std::thread::spawn(|| {})) };
+    /// assert!(adopted.completion().is_none());
+    /// ```
+    pub fn completion(&self) -> Option<CompletionFuture<'_>> {
+        self.completion
+            .as_deref()
+            .map(|completion| CompletionFuture {
+                completion,
+                key: None,
+            })
+    }
+
     pub fn into_rc(self) -> Rc<Self> {
         // SAFETY: we cannot move Rc<JoinGuard> into it's closure because
         //   impl !Send for Rc<JoinGuard>
@@ -85,6 +651,132 @@ impl<T> JoinGuard<'_, T> {
         //   Arc<JoinGuard>: !Send, or otherwise JoinGuard: Forget
         unsafe { Arc::new_unchecked(self) }
     }
+
+    /// Erases the output type, to store guards of different outputs together.
+    ///
+    /// The boxed guard still joins on drop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use leak_playground_std::thread::{self, GuardHandle};
+    ///
+    /// let done = AtomicUsize::new(0);
+    /// let guards: Vec<Box<dyn GuardHandle + '_>> = vec![
+    ///     thread::spawn_scoped(|| done.fetch_add(1, Ordering::Relaxed)).into_dyn(),
+    ///     thread::spawn_scoped(|| {
+    ///         done.fetch_add(1, Ordering::Relaxed);
+    ///         String::from("done")
+    ///     })
+    ///     .into_dyn(),
+    /// ];
+    /// drop(guards);
+    /// assert_eq!(done.load(Ordering::Relaxed), 2);
+    /// ```
+    pub fn into_dyn(self) -> Box<dyn GuardHandle + 'a>
+    where
+        T: 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Erases the output type like [`JoinGuard::into_dyn`], but keeps the
+    /// ability to join. The output is dropped on join.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_std::thread::{self, BoxedGuard};
+    ///
+    /// let local = 42;
+    /// let guards: Vec<BoxedGuard<'_>> = vec![
+    ///     thread::spawn_scoped(|| local + 1).erase(),
+    ///     thread::spawn_scoped(|| local.to_string()).erase(),
+    ///     thread::spawn_scoped(|| panic!()).erase(),
+    /// ];
+    /// let results: Vec<_> = guards.into_iter().map(|thrd| thrd.join().is_ok()).collect();
+    /// assert_eq!(results, [true, true, false]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use leak_playground_std::{mem, thread};
+    ///
+    /// let local = 42;
+    /// mem::forget(thread::spawn_scoped(|| local).erase());
+    /// ```
+    pub fn erase(self) -> BoxedGuard<'a>
+    where
+        T: 'a,
+    {
+        BoxedGuard {
+            inner: Box::new(self),
+        }
+    }
+}
+
+/// Guard with an erased output type, see [`JoinGuard::erase`].
+///
+/// Joins on drop, and just like [`JoinGuard`] cannot be sent across threads
+/// or forgotten.
+pub struct BoxedGuard<'a> {
+    inner: Box<dyn ErasedGuard + 'a>,
+}
+
+impl BoxedGuard<'_> {
+    /// Joins the thread, dropping its output.
+    pub fn join(self) -> std::thread::Result<()> {
+        self.inner.join()
+    }
+
+    pub fn thread(&self) -> &std::thread::Thread {
+        self.inner.thread()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.inner.is_finished()
+    }
+}
+
+impl Unforgettable for BoxedGuard<'_> {}
+
+impl GuardHandle for BoxedGuard<'_> {
+    fn is_finished(&self) -> bool {
+        BoxedGuard::is_finished(self)
+    }
+
+    fn thread(&self) -> &std::thread::Thread {
+        BoxedGuard::thread(self)
+    }
+}
+
+trait ErasedGuard: GuardHandle {
+    fn join(self: Box<Self>) -> std::thread::Result<()>;
+}
+
+impl<T> ErasedGuard for JoinGuard<'_, T> {
+    fn join(self: Box<Self>) -> std::thread::Result<()> {
+        JoinGuard::join(*self).map(drop)
+    }
+}
+
+/// Object safe part of the [`JoinGuard`] interface, see
+/// [`JoinGuard::into_dyn`].
+pub trait GuardHandle {
+    fn is_finished(&self) -> bool;
+    fn thread(&self) -> &std::thread::Thread;
+}
+
+impl<T> Unforgettable for JoinGuard<'_, T> {}
+
+impl<T> GuardHandle for JoinGuard<'_, T> {
+    fn is_finished(&self) -> bool {
+        JoinGuard::is_finished(self)
+    }
+
+    fn thread(&self) -> &std::thread::Thread {
+        JoinGuard::thread(self)
+    }
 }
 
 impl<T> JoinGuard<'static, T> {
@@ -98,18 +790,26 @@ impl<T> JoinGuard<'static, T> {
 }
 
 impl<T> From<JoinGuard<'static, T>> for JoinHandle<T> {
-    fn from(mut value: JoinGuard<'static, T>) -> Self {
-        unsafe { ManuallyDrop::take(&mut value.child) }
+    fn from(value: JoinGuard<'static, T>) -> Self {
+        // SAFETY: `JoinGuard<'static, T>` is `Forget`
+        unsafe { value.take_child() }
     }
 }
 
 impl<'a, T> Drop for JoinGuard<'a, T> {
     fn drop(&mut self) {
+        if let Some(cancel) = &self.cancel {
+            cancel.cancel();
+        }
         let join_handle = unsafe { ManuallyDrop::take(&mut self.child) };
         // Shouldn't panic
         let child = join_handle.thread().clone();
-        // No panic since we guarantee that we would never join on ourselves,
-        // except when `Self: Forget`, then we don't care.
+        // We guarantee that we would never join on ourselves, except when
+        // `Self: Forget` or the guarantee is broken by unsafe code. Joining
+        // would deadlock then, so detaching instead, like forgetting would.
+        if child.id() == thread::current().id() {
+            return;
+        }
         let res = join_handle.join();
         // Propagating panic there since structured parallelism, but ignoring
         // during panic. Anyway child thread is joined thus either would
@@ -119,3 +819,211 @@ impl<'a, T> Drop for JoinGuard<'a, T> {
         }
     }
 }
+
+/// Guards looked up by their thread's id.
+///
+/// Dropping the registry joins every remaining thread, even if some of them
+/// have panicked. Then the first panic is propagated, unless the current
+/// thread is already panicking.
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_std::thread::{self, GuardRegistry};
+///
+/// let local = 40;
+/// let mut registry = GuardRegistry::new();
+/// let first = registry.insert(thread::spawn_scoped(|| local + 1));
+/// registry.insert(thread::spawn_scoped(|| local + 2));
+/// assert_eq!(registry.join(first).unwrap().unwrap(), 41);
+/// assert!(registry.join(first).is_none());
+/// let rest: Vec<_> = registry
+///     .join_all()
+///     .into_iter()
+///     .map(|(_, res)| res.unwrap())
+///     .collect();
+/// assert_eq!(rest, [42]);
+/// ```
+///
+/// Registry is unforgettable as long as its guards are:
+///
+/// ```compile_fail
+/// use leak_playground_std::{mem, thread::{self, GuardRegistry}};
+///
+/// let local = 42;
+/// let mut registry = GuardRegistry::new();
+/// registry.insert(thread::spawn_scoped(|| local + 1));
+/// mem::forget(registry);
+/// ```
+///
+/// A panicking thread doesn't stop others from being joined:
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+/// use leak_playground_std::thread::{self, GuardRegistry};
+///
+/// let finished = AtomicUsize::new(0);
+/// let res = std::panic::catch_unwind(|| {
+///     let mut registry = GuardRegistry::new();
+///     registry.insert(thread::spawn_scoped(|| panic!("oops")));
+///     for _ in 0..8 {
+///         registry.insert(thread::spawn_scoped(|| {
+///             thread::sleep(Duration::from_millis(10));
+///             finished.fetch_add(1, Ordering::Relaxed);
+///         }));
+///     }
+/// });
+/// assert!(res.is_err());
+/// assert_eq!(finished.load(Ordering::Relaxed), 8);
+/// ```
+pub struct GuardRegistry<'a, T> {
+    guards: HashMap<thread::ThreadId, JoinGuard<'a, T>>,
+}
+
+impl<'a, T> GuardRegistry<'a, T> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        GuardRegistry {
+            guards: HashMap::new(),
+        }
+    }
+
+    /// Adds the guard, returning its thread's id.
+    pub fn insert(&mut self, guard: JoinGuard<'a, T>) -> thread::ThreadId {
+        let id = guard.thread().id();
+        self.guards.insert(id, guard);
+        id
+    }
+
+    /// Returns the guard of the thread with `id`.
+    pub fn get(&self, id: thread::ThreadId) -> Option<&JoinGuard<'a, T>> {
+        self.guards.get(&id)
+    }
+
+    /// Takes out the guard without joining it.
+    pub fn remove(&mut self, id: thread::ThreadId) -> Option<JoinGuard<'a, T>> {
+        self.guards.remove(&id)
+    }
+
+    /// Joins the thread with `id`, if it is in the registry.
+    pub fn join(&mut self, id: thread::ThreadId) -> Option<std::thread::Result<T>> {
+        self.remove(id).map(JoinGuard::join)
+    }
+
+    /// Joins all of the threads.
+    pub fn join_all(&mut self) -> Vec<(thread::ThreadId, std::thread::Result<T>)> {
+        self.guards
+            .drain()
+            .map(|(id, guard)| (id, guard.join()))
+            .collect()
+    }
+
+    /// Returns the number of guards in the registry.
+    pub fn len(&self) -> usize {
+        self.guards.len()
+    }
+
+    /// Returns `true` if the registry has no guards.
+    pub fn is_empty(&self) -> bool {
+        self.guards.is_empty()
+    }
+}
+
+impl<T> Drop for GuardRegistry<'_, T> {
+    fn drop(&mut self) {
+        // Not relying on the map's drop, which would stop dropping remaining
+        // guards after the first one panics, leaking them
+        let mut panic = None;
+        for (_, guard) in self.guards.drain() {
+            if let Err(payload) = guard.join() {
+                panic.get_or_insert(payload);
+            }
+        }
+        if let Some(payload) = panic {
+            if !std::thread::panicking() {
+                std::panic::resume_unwind(payload);
+            }
+        }
+    }
+}
+
+impl<T> Default for GuardRegistry<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+type Job<'a> = Box<dyn FnOnce() + Send + 'a>;
+
+/// A borrowing thread running jobs one after another.
+///
+/// Jobs only need to be `Send` themselves, so they may construct `!Send`
+/// state like [`std::rc::Rc`] on the worker. The thread is joined on drop,
+/// after running the remaining jobs.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+/// use leak_playground_std::thread::LocalWorker;
+///
+/// let base = 40;
+/// let worker = LocalWorker::new();
+/// let sum = worker
+///     .run(|| {
+///         let shared = Rc::new(base);
+///         let other = Rc::clone(&shared);
+///         *shared + *other / 20
+///     })
+///     .unwrap();
+/// assert_eq!(sum, 42);
+/// assert!(worker.run(|| panic!()).is_err());
+/// assert_eq!(worker.run(|| base + 2).unwrap(), 42);
+/// ```
+pub struct LocalWorker<'a> {
+    // Dropped before the guard, so the thread stops receiving
+    jobs: std::sync::mpsc::Sender<Job<'a>>,
+    thrd: JoinGuard<'a, ()>,
+}
+
+impl<'a> LocalWorker<'a> {
+    pub fn new() -> Self {
+        let (jobs, rx) = std::sync::mpsc::channel::<Job<'a>>();
+        let thrd = spawn_scoped(move || {
+            for job in rx {
+                job();
+            }
+        });
+        LocalWorker { jobs, thrd }
+    }
+
+    /// Runs `f` on the worker, blocking until it's done.
+    ///
+    /// Panics are caught and returned as an error, keeping the worker alive.
+    pub fn run<F, R>(&self, f: F) -> std::thread::Result<R>
+    where
+        F: FnOnce() -> R + Send + 'a,
+        R: Send + 'a,
+    {
+        let (tx, rx) = std::sync::mpsc::sync_channel(1);
+        let job: Job<'a> = Box::new(move || {
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+            let _ = tx.send(res);
+        });
+        self.jobs
+            .send(job)
+            .expect("worker thread has stopped unexpectedly");
+        rx.recv().expect("worker thread has stopped unexpectedly")
+    }
+
+    pub fn thread(&self) -> &thread::Thread {
+        self.thrd.thread()
+    }
+}
+
+impl Default for LocalWorker<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
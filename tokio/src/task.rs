@@ -1,8 +1,10 @@
 //! Possible [`tokio::task`](https://docs.rs/tokio/1.35.1/tokio/task/index.html) additions.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::{future::Future, marker::PhantomData, pin::Pin, ptr::NonNull};
 
-use leak_playground_std::marker::Unforget;
+use leak_playground_std::marker::{Forget, Unforget, Unforgettable};
 use leak_playground_std::mem::ManuallyDrop;
 use tokio::task::{AbortHandle, JoinError, JoinHandle};
 
@@ -16,12 +18,45 @@ where
         inner: unsafe {
             ManuallyDrop::new_unchecked(tokio::task::spawn(erased_send_future(future)))
         },
+        cancelled: None,
+        name: None,
+        completed: false,
+        abort_on_drop: true,
+        map: None,
         _unforget: Unforget::new(PhantomData),
         _unsend: PhantomData,
         _output: PhantomData,
     }
 }
 
+/// Spawns a non-static `Send` future like [`spawn_scoped`], naming the handle.
+///
+/// The name is only stored in the handle, see [`ScopedJoinHandle::name`].
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_tokio::task;
+///
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// rt.block_on(async {
+///     let local = 42;
+///     let handle = task::spawn_scoped_named("answer", async { local });
+///     assert_eq!(handle.name(), Some("answer"));
+///     assert_eq!(task::spawn_scoped(async {}).name(), None);
+///     assert_eq!(handle.cancel().await.is_ok(), true);
+/// });
+/// ```
+pub fn spawn_scoped_named<'a, F>(name: &str, future: F) -> ScopedJoinHandle<'a, F::Output>
+where
+    F: Future + Send + 'a,
+    F::Output: Send + 'a,
+{
+    let mut handle = spawn_scoped(future);
+    handle.name = Some(name.into());
+    handle
+}
+
 /// Spawns a non-static `!Send` future.
 pub fn spawn_local_scoped<'a, F>(future: F) -> ScopedJoinHandle<'a, F::Output>
 where
@@ -32,6 +67,11 @@ where
         inner: unsafe {
             ManuallyDrop::new_unchecked(tokio::task::spawn_local(erased_future(future)))
         },
+        cancelled: None,
+        name: None,
+        completed: false,
+        abort_on_drop: true,
+        map: None,
         _unforget: Unforget::new(PhantomData),
         _unsend: PhantomData,
         _output: PhantomData,
@@ -48,6 +88,101 @@ where
         inner: unsafe {
             ManuallyDrop::new_unchecked(tokio::task::spawn_blocking(erased_send_fn_once(f)))
         },
+        cancelled: None,
+        name: None,
+        completed: false,
+        abort_on_drop: true,
+        map: None,
+        _unforget: Unforget::new(PhantomData),
+        _unsend: PhantomData,
+        _output: PhantomData,
+    }
+}
+
+/// Runs the provided non-static closure on a thread where blocking is
+/// acceptable, sending its output on the returned channel, so it can be
+/// observed from sync code.
+///
+/// The handle still aborts on drop. If that happens before the closure has
+/// started, it never runs and the receiver observes disconnection. Once
+/// started, the closure runs to completion, same as with
+/// [`spawn_blocking_scoped`].
+///
+/// # Examples
+///
+/// ```
+/// use leak_playground_tokio::task;
+///
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// rt.block_on(async {
+///     let numbers = vec![1, 2, 3];
+///     let (handle, rx) = task::spawn_blocking_scoped_channel(|| numbers.iter().sum::<i32>());
+///     assert_eq!(rx.recv(), Ok(6));
+///     handle.await.unwrap();
+/// });
+/// ```
+pub fn spawn_blocking_scoped_channel<'a, F, T>(
+    f: F,
+) -> (ScopedJoinHandle<'a, ()>, leak_playground_flume::Receiver<T>)
+where
+    F: FnOnce() -> T + Send + 'a,
+    T: Send + Forget + 'a,
+{
+    let (tx, rx) = leak_playground_flume::bounded(1);
+    let handle = spawn_blocking_scoped(move || {
+        // Receiver may be gone already, which is fine
+        let _ = tx.send(f());
+    });
+    (handle, rx)
+}
+
+/// Runs the provided non-static closure on a thread where blocking is
+/// acceptable, passing it a flag which is set once the task is aborted.
+///
+/// Aborting cannot interrupt blocking code, so the closure should check the
+/// flag periodically and return early once it is set. Otherwise dropping the
+/// handle waits for the closure to complete.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::Ordering;
+/// use std::time::Duration;
+/// use leak_playground_tokio::task;
+///
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// rt.block_on(async {
+///     let (tx, rx) = std::sync::mpsc::channel();
+///     let handle = task::spawn_blocking_cancellable(|cancelled| {
+///         tx.send(()).unwrap();
+///         while !cancelled.load(Ordering::Relaxed) {
+///             std::thread::sleep(Duration::from_millis(1));
+///         }
+///     });
+///     rx.recv().unwrap();
+///     // Returns as soon as the loop notices the flag
+///     drop(handle);
+/// });
+/// ```
+pub fn spawn_blocking_cancellable<'a, F, T>(f: F) -> ScopedJoinHandle<'a, T>
+where
+    F: FnOnce(&AtomicBool) -> T + Send + 'a,
+    T: Send + 'a,
+{
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let f = {
+        let cancelled = Arc::clone(&cancelled);
+        move || f(&cancelled)
+    };
+    ScopedJoinHandle {
+        inner: unsafe {
+            ManuallyDrop::new_unchecked(tokio::task::spawn_blocking(erased_send_fn_once(f)))
+        },
+        cancelled: Some(cancelled),
+        name: None,
+        completed: false,
+        abort_on_drop: true,
+        map: None,
         _unforget: Unforget::new(PhantomData),
         _unsend: PhantomData,
         _output: PhantomData,
@@ -58,19 +193,83 @@ where
 ///
 /// This is made to ensure we won't put task into itself, thus forgetting it.
 ///
-/// To spawn use [`spawn_scoped`], [`spawn_local_scoped`], or
-/// [`spawn_blocking_scoped`].
+/// To spawn use [`spawn_scoped`], [`spawn_scoped_named`],
+/// [`spawn_local_scoped`], [`spawn_blocking_scoped`], or
+/// [`spawn_blocking_cancellable`].
+///
+/// # Thread safety
+///
+/// Only `'static` handles are `Send`, so a borrowing task can never receive
+/// its own handle:
+///
+/// ```
+/// use leak_playground_tokio::task::ScopedJoinHandle;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<ScopedJoinHandle<'static, i32>>();
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_tokio::task::ScopedJoinHandle;
+/// fn assert_send<T: Send>() {}
+/// fn _non_static<'a>() {
+///     assert_send::<ScopedJoinHandle<'a, i32>>();
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_tokio::task::ScopedJoinHandle;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<ScopedJoinHandle<'static, std::rc::Rc<i32>>>();
+/// ```
+///
+/// Handles of any lifetime are `Sync`, since a shared reference only allows
+/// to abort the task:
+///
+/// ```
+/// use leak_playground_tokio::task::ScopedJoinHandle;
+/// fn assert_sync<T: Sync>() {}
+/// fn _non_static<'a>() {
+///     assert_sync::<ScopedJoinHandle<'a, i32>>();
+/// }
+/// assert_sync::<ScopedJoinHandle<'static, i32>>();
+/// ```
+///
+/// ```compile_fail
+/// use leak_playground_tokio::task::ScopedJoinHandle;
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<ScopedJoinHandle<'static, std::rc::Rc<i32>>>();
+/// ```
 pub struct ScopedJoinHandle<'a, T> {
     inner: ManuallyDrop<JoinHandle<Payload>>,
+    /// Cooperative cancellation flag of [`spawn_blocking_cancellable`] tasks
+    cancelled: Option<Arc<AtomicBool>>,
+    /// See [`spawn_scoped_named`]
+    name: Option<Box<str>>,
+    /// Output was already taken, so there's nothing to wait for on drop
+    completed: bool,
+    /// Only `'static` handles may turn it off, see
+    /// [`ScopedJoinHandle::set_abort_on_drop`]
+    abort_on_drop: bool,
+    /// Converts the task's output into `T`, see [`ScopedJoinHandle::map`]
+    map: Option<Mapper<'a>>,
     _unforget: Unforget<'static, PhantomData<&'a ()>>,
     // No need for Unforget since we put bound `T: 'a` on constructors
     _output: PhantomData<T>,
     _unsend: PhantomData<*mut ()>,
 }
 
+type Mapper<'a> = Box<dyn FnOnce(Payload) -> Payload + Send + 'a>;
+
+// SAFETY: `'static` task cannot borrow anything, so it is fine to move its
+//   handle anywhere, even into the task itself. Non-`'static` handles stay
+//   `!Send` to prevent exactly that.
 unsafe impl<T: Send> Send for ScopedJoinHandle<'static, T> {}
+// SAFETY: through `&ScopedJoinHandle` one may only abort the task or get an
+//   `AbortHandle`, both of which are thread-safe. Polling and dropping, which
+//   touch the output and runtime, require ownership or `&mut`.
 unsafe impl<T: Send> Sync for ScopedJoinHandle<'_, T> {}
 impl<T> Unpin for ScopedJoinHandle<'_, T> {}
+impl<T> Unforgettable for ScopedJoinHandle<'_, T> {}
 
 impl<'a, T> Future for ScopedJoinHandle<'a, T> {
     type Output = Result<T, JoinError>;
@@ -79,23 +278,225 @@ impl<'a, T> Future for ScopedJoinHandle<'a, T> {
         mut self: Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Self::Output> {
-        JoinHandle::poll(Pin::new(&mut self.inner), cx)
-            .map(|r| r.map(|r| unsafe { r.get_unchecked::<T>() }))
+        assert!(
+            !self.completed,
+            "`ScopedJoinHandle` polled after completion"
+        );
+        let res = std::task::ready!(JoinHandle::poll(Pin::new(&mut self.inner), cx));
+        self.completed = true;
+        let map = self.map.take();
+        std::task::Poll::Ready(res.map(|r| unsafe { unerase::<T>(map, r) }))
     }
 }
 
+/// Outcome of [`ScopedJoinHandle::cancel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CancelOutcome<T> {
+    /// The task was aborted before it could complete.
+    Cancelled,
+    /// The task has completed before the abort took effect.
+    CompletedBeforeCancel(T),
+    /// The output was already taken by polling the handle, e.g. with
+    /// [`ScopedJoinHandle::try_join`].
+    AlreadyJoined,
+}
+
 impl<'a, T> ScopedJoinHandle<'a, T> {
-    pub async fn cancel(mut self) -> Result<(), JoinError> {
-        self.inner.abort();
-        let task = unsafe { ManuallyDrop::take(&mut self.inner) };
+    /// Aborts the task and waits for it to stop.
+    ///
+    /// Task may complete right before it's aborted, in that case the output is
+    /// returned as [`CancelOutcome::CompletedBeforeCancel`] instead of being
+    /// dropped. If the task has panicked, the panic is returned as an error.
+    /// If the output was already taken, returns [`CancelOutcome::AlreadyJoined`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_tokio::task::{self, CancelOutcome};
+    ///
+    /// let rt = tokio::runtime::Runtime::new().unwrap();
+    /// rt.block_on(async {
+    ///     let handle = task::spawn_scoped(std::future::pending::<()>());
+    ///     assert_eq!(handle.cancel().await.unwrap(), CancelOutcome::Cancelled);
+    ///
+    ///     let local = 42;
+    ///     let handle = task::spawn_scoped(async { local + 1 });
+    ///     while !handle.abort_handle().is_finished() {
+    ///         tokio::task::yield_now().await;
+    ///     }
+    ///     assert_eq!(
+    ///         handle.cancel().await.unwrap(),
+    ///         CancelOutcome::CompletedBeforeCancel(43),
+    ///     );
+    ///
+    ///     let mut handle = task::spawn_scoped(async { local });
+    ///     while handle.try_join().is_none() {
+    ///         tokio::task::yield_now().await;
+    ///     }
+    ///     assert_eq!(handle.cancel().await.unwrap(), CancelOutcome::AlreadyJoined);
+    /// });
+    /// ```
+    pub async fn cancel(mut self) -> Result<CancelOutcome<T>, JoinError> {
+        if self.completed {
+            // SAFETY: the task has completed
+            drop(unsafe { self.take_inner() });
+            return Ok(CancelOutcome::AlreadyJoined);
+        }
+        self.abort();
+        let map = self.map.take();
+        // SAFETY: we await the task right after
+        let task = unsafe { self.take_inner() };
         match task.await {
-            Err(e) if e.is_cancelled() => Ok(()),
-            Ok(_) => Ok(()),
+            Err(e) if e.is_cancelled() => Ok(CancelOutcome::Cancelled),
+            Ok(r) => Ok(CancelOutcome::CompletedBeforeCancel(unsafe {
+                unerase::<T>(map, r)
+            })),
             Err(e) => Err(e),
         }
     }
 
+    /// Takes out the task handle without awaiting it.
+    ///
+    /// # Safety
+    ///
+    /// The task must be awaited unless `'a` is `'static`.
+    unsafe fn take_inner(mut self) -> JoinHandle<Payload> {
+        let task = ManuallyDrop::take(&mut self.inner);
+        drop(self.cancelled.take());
+        drop(self.name.take());
+        drop(self.map.take());
+        // need this to avoid calling `ScopedJoinHandle::drop`
+        leak_playground_std::mem::forget_unchecked(self);
+        task
+    }
+
+    /// Converts the output once the task completes.
+    ///
+    /// The task is still aborted when the returned handle is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output was already taken, since `f` could never run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_tokio::task;
+    ///
+    /// let rt = tokio::runtime::Runtime::new().unwrap();
+    /// rt.block_on(async {
+    ///     let local = 42;
+    ///     let handle = task::spawn_scoped(async { local }).map(|x| x.to_string());
+    ///     assert_eq!(handle.await.unwrap(), "42");
+    ///
+    ///     let (tx, rx) = std::sync::mpsc::channel::<()>();
+    ///     let handle = task::spawn_scoped(async move {
+    ///         let _tx = tx;
+    ///         std::future::pending::<()>().await
+    ///     })
+    ///     .map(|()| local);
+    ///     drop(handle);
+    ///     // Aborted task has dropped the sender
+    ///     assert!(rx.recv().is_err());
+    /// });
+    /// ```
+    ///
+    /// ```should_panic
+    /// use leak_playground_tokio::task;
+    ///
+    /// let rt = tokio::runtime::Runtime::new().unwrap();
+    /// rt.block_on(async {
+    ///     let mut handle = task::spawn_scoped(async { 42 });
+    ///     while handle.try_join().is_none() {
+    ///         tokio::task::yield_now().await;
+    ///     }
+    ///     handle.map(|x| x + 1);
+    /// });
+    /// ```
+    pub fn map<U, F>(mut self, f: F) -> ScopedJoinHandle<'a, U>
+    where
+        F: FnOnce(T) -> U + Send + 'a,
+        U: 'a,
+    {
+        assert!(
+            !self.completed,
+            "`ScopedJoinHandle::map` called after completion"
+        );
+        let prev = self.map.take();
+        let map: Mapper<'a> = Box::new(move |payload| {
+            // SAFETY: `prev` produces `T`, or otherwise the task does
+            unsafe { Payload::new_unchecked(f(unerase::<T>(prev, payload))) }
+        });
+        let cancelled = self.cancelled.take();
+        let name = self.name.take();
+        let abort_on_drop = self.abort_on_drop;
+        ScopedJoinHandle {
+            // SAFETY: the new handle awaits the task instead
+            inner: unsafe { ManuallyDrop::new_unchecked(self.take_inner()) },
+            cancelled,
+            name,
+            completed: false,
+            abort_on_drop,
+            map: Some(map),
+            _unforget: Unforget::new(PhantomData),
+            _unsend: PhantomData,
+            _output: PhantomData,
+        }
+    }
+
+    /// Takes the output if the task has completed, without blocking.
+    ///
+    /// This polls the handle once with a no-op waker, so it is cancellation
+    /// safe: on `None` nothing is lost, and the handle can be polled, awaited
+    /// or tried again later. Once the output is returned, the handle behaves
+    /// like a completed future and must not be polled again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_tokio::task;
+    ///
+    /// let rt = tokio::runtime::Runtime::new().unwrap();
+    /// rt.block_on(async {
+    ///     let (tx, rx) = std::sync::mpsc::channel();
+    ///     let local = 42;
+    ///     let mut handle = task::spawn_blocking_scoped(move || {
+    ///         rx.recv().unwrap();
+    ///         local + 1
+    ///     });
+    ///     assert!(handle.try_join().is_none());
+    ///     tx.send(()).unwrap();
+    ///     assert_eq!(handle.await.unwrap(), 43);
+    ///
+    ///     let mut handle = task::spawn_scoped(async { local });
+    ///     let res = loop {
+    ///         if let Some(res) = handle.try_join() {
+    ///             break res;
+    ///         }
+    ///         tokio::task::yield_now().await;
+    ///     };
+    ///     assert_eq!(res.unwrap(), 42);
+    ///     // Dropping after completion doesn't wait for the task again
+    ///     drop(handle);
+    /// });
+    /// ```
+    pub fn try_join(&mut self) -> Option<Result<T, JoinError>> {
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        match Pin::new(self).poll(&mut cx) {
+            std::task::Poll::Ready(res) => Some(res),
+            std::task::Poll::Pending => None,
+        }
+    }
+
+    /// Name given to [`spawn_scoped_named`], if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     pub fn abort(&self) {
+        if let Some(cancelled) = &self.cancelled {
+            cancelled.store(true, Ordering::Relaxed);
+        }
         self.inner.abort();
     }
 
@@ -104,13 +505,55 @@ impl<'a, T> ScopedJoinHandle<'a, T> {
     }
 }
 
+impl<T> ScopedJoinHandle<'static, T> {
+    /// Chooses whether dropping the handle aborts and waits for the task, or
+    /// detaches it to keep running. Aborts by default.
+    ///
+    /// Only `'static` tasks can be detached, since they don't borrow
+    /// anything. Scoped handles always abort on drop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use leak_playground_tokio::task;
+    ///
+    /// let rt = tokio::runtime::Runtime::new().unwrap();
+    /// rt.block_on(async {
+    ///     let (tx, rx) = std::sync::mpsc::channel::<()>();
+    ///     let (done_tx, done_rx) = std::sync::mpsc::channel::<()>();
+    ///     let mut handle = task::spawn_scoped(async move {
+    ///         while rx.try_recv().is_err() {
+    ///             tokio::task::yield_now().await;
+    ///         }
+    ///         done_tx.send(()).unwrap();
+    ///     });
+    ///     handle.set_abort_on_drop(false);
+    ///     drop(handle);
+    ///     tx.send(()).unwrap();
+    ///     // Sender would be dropped without sending if the task was aborted
+    ///     done_rx.recv().unwrap();
+    /// });
+    /// ```
+    pub fn set_abort_on_drop(&mut self, yes: bool) {
+        self.abort_on_drop = yes;
+    }
+}
+
 // TODO: `impl<T> From<ScopedJoinHandle<'static, T>> for JoinHandle<T>`
 //  is possible but requires internals to avoid hacky `Payload` return type
 
 impl<'a, T> Drop for ScopedJoinHandle<'a, T> {
     fn drop(&mut self) {
-        self.inner.abort();
+        if !self.abort_on_drop {
+            // Detaching, which is fine since only `'static` tasks get here
+            drop(unsafe { ManuallyDrop::take(&mut self.inner) });
+            return;
+        }
+        self.abort();
         let task = unsafe { ManuallyDrop::take(&mut self.inner) };
+        if self.completed {
+            return;
+        }
         // TODO: this is a hack-around without async drop
         tokio::task::block_in_place(move || {
             tokio::runtime::Handle::current().block_on(async move {
@@ -126,6 +569,19 @@ impl<'a, T> Drop for ScopedJoinHandle<'a, T> {
 
 // # Hack-around utilities
 
+/// Converts the task's output into `T`.
+///
+/// # Safety
+///
+/// Without `map` the payload must hold `T`, otherwise `map` must produce `T`.
+unsafe fn unerase<T>(map: Option<Mapper<'_>>, payload: Payload) -> T {
+    match map {
+        Some(map) => map(payload),
+        None => payload,
+    }
+    .get_unchecked::<T>()
+}
+
 unsafe fn erased_send_fn_once<F, R>(f: F) -> impl FnOnce() -> Payload + Send + 'static
 where
     F: FnOnce() -> R + Send,
@@ -158,6 +614,7 @@ where
 
 struct Payload {
     ptr: NonNull<()>,
+    drop: unsafe fn(NonNull<()>),
 }
 
 unsafe impl Send for Payload {}
@@ -167,10 +624,24 @@ impl Payload {
     unsafe fn new_unchecked<T>(v: T) -> Payload {
         Payload {
             ptr: NonNull::new_unchecked(Box::into_raw(Box::new(v)).cast()),
+            drop: Payload::drop_unchecked::<T>,
         }
     }
 
     unsafe fn get_unchecked<T>(self) -> T {
-        *Box::from_raw(self.ptr.cast().as_ptr())
+        let ptr = self.ptr;
+        std::mem::forget(self);
+        *Box::from_raw(ptr.cast().as_ptr())
+    }
+
+    unsafe fn drop_unchecked<T>(ptr: NonNull<()>) {
+        drop(Box::<T>::from_raw(ptr.cast().as_ptr()))
+    }
+}
+
+impl Drop for Payload {
+    fn drop(&mut self) {
+        // SAFETY: `drop` was instantiated with the type behind `ptr`
+        unsafe { (self.drop)(self.ptr) }
     }
 }